@@ -1,9 +1,6 @@
-use anyhow::{Context, Error, Result};
-use aoc2024::init;
-use itertools::process_results;
-use regex::Regex;
+use anyhow::{Error, Result};
+use aoc2024::{init, try_parse};
 use std::str::FromStr;
-use std::sync::LazyLock;
 
 init!("11", "31");
 
@@ -21,21 +18,11 @@ struct Lists {
     right: Vec<i32>,
 }
 
-static RE_INPUT: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(\d+)\s+(\d+)$").unwrap());
-
 impl FromStr for Lists {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        let results_iter = s.lines().map(|line| {
-            let caps = RE_INPUT.captures(line).context("input regex match error")?;
-            let a = caps.get(1).unwrap().as_str().parse::<i32>()?;
-            let b = caps.get(2).unwrap().as_str().parse::<i32>()?;
-
-            Ok::<(i32, i32), Error>((a, b))
-        });
-        let (mut left, mut right): (Vec<i32>, Vec<i32>) =
-            process_results(results_iter, |iter| iter.unzip())?;
+        let (mut left, mut right): (Vec<i32>, Vec<i32>) = try_parse!([(i32 ws i32) lines])(s)?.into_iter().unzip();
 
         left.sort();
         right.sort();
@@ -46,11 +33,7 @@ impl FromStr for Lists {
 
 impl Lists {
     fn total_distance(&self) -> i32 {
-        self.left
-            .iter()
-            .zip(&self.right)
-            .map(|(l, r)| (l - r).abs())
-            .sum()
+        self.left.iter().zip(&self.right).map(|(l, r)| (l - r).abs()).sum()
     }
 
     fn similarity_score(&self) -> i32 {
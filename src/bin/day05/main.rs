@@ -1,5 +1,5 @@
-use anyhow::{Context, Error, Result};
-use aoc2024::init;
+use anyhow::{Error, Result};
+use aoc2024::{init, try_parse};
 use std::iter::Iterator;
 use std::str::FromStr;
 
@@ -23,24 +23,7 @@ impl FromStr for Rules {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        let (input_ordering, input_updates) = s.split_once("\n\n").context("input split error")?;
-
-        let ordering: Vec<(i32, i32)> = input_ordering
-            .lines()
-            .map(|line| {
-                let (l, r) = line.split_once('|').context("input split error")?;
-                Ok((l.parse::<i32>()?, r.parse::<i32>()?))
-            })
-            .collect::<Result<_>>()?;
-
-        let updates: Vec<Vec<i32>> = input_updates
-            .lines()
-            .map(|line| {
-                line.split(',')
-                    .map(|elem| elem.parse::<i32>())
-                    .collect::<Result<_, _>>()
-            })
-            .collect::<Result<_, _>>()?;
+        let (ordering, updates) = try_parse!(([(i32 "|" i32) lines] sections [[i32 ","] lines]))(s)?;
 
         Ok(Rules { ordering, updates })
     }
@@ -1,5 +1,5 @@
 use anyhow::{Error, Result};
-use aoc2024::init;
+use aoc2024::{init, try_parse};
 use std::iter::Iterator;
 use std::str::FromStr;
 use strum::{EnumIter, IntoEnumIterator};
@@ -30,18 +30,7 @@ impl FromStr for Data {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        let equations = s
-            .lines()
-            .map(|line| {
-                let (l, r) = line.split_once(": ").unwrap();
-                (
-                    l.parse::<i64>().unwrap(),
-                    r.split(" ")
-                        .map(|elem| elem.parse::<i64>().unwrap())
-                        .collect(),
-                )
-            })
-            .collect();
+        let equations = try_parse!([(i64 ": " [i64 ws]) lines])(s)?;
         Ok(Data { equations })
     }
 }
@@ -1,7 +1,7 @@
 use anyhow::{Error, Result};
 use aoc2024::init;
+use aoc2024::util::grid::{Coord, Grid, SparseGrid};
 use itertools::Itertools;
-use num::integer::gcd;
 use std::collections::{HashMap, HashSet};
 use std::iter::Iterator;
 use std::str::FromStr;
@@ -18,53 +18,39 @@ fn run(input: &str) -> Result<(String, String)> {
 }
 
 struct State {
-    size: (usize, usize),
-    antennas: HashMap<char, Vec<(usize, usize)>>,
+    grid: Grid<char>,
+    antennas: SparseGrid<char>,
 }
 
 impl FromStr for State {
     type Err = Error;
 
     fn from_str(s: &str) -> Result<Self> {
-        let mut antennas: HashMap<char, Vec<(usize, usize)>> = HashMap::new();
-        let mut size = (0, 0);
+        let grid: Grid<char> = Grid::from_str(s);
+        let antennas = SparseGrid::from_grid(grid.clone(), Coord(0, 0), |&c| c != '.');
 
-        for (row, line) in s.lines().enumerate() {
-            for (col, c) in line.chars().enumerate() {
-                if c != '.' {
-                    antennas.entry(c).or_default().push((row, col));
-                }
-                size = (row + 1, col + 1);
-            }
-        }
-
-        Ok(State { size, antennas })
+        Ok(State { grid, antennas })
     }
 }
 
 impl State {
+    fn frequencies(&self) -> HashMap<char, Vec<Coord>> {
+        self.antennas.indexed_iter().map(|(idx, &c)| (c, idx)).into_group_map()
+    }
+
     pub fn find_antinodes(&self) -> i32 {
         let mut antinodes = HashSet::new();
 
-        for frequency in self.antennas.values() {
-            for (antenna_a, antenna_b) in frequency.iter().tuple_combinations() {
-                let diff = (
-                    antenna_b.0 as isize - antenna_a.0 as isize,
-                    antenna_b.1 as isize - antenna_a.1 as isize,
-                );
-                let antinode_a = (
-                    antenna_a.0.wrapping_add_signed(-diff.0),
-                    antenna_a.1.wrapping_add_signed(-diff.1),
-                );
-                let antinode_b = (
-                    antenna_b.0.wrapping_add_signed(diff.0),
-                    antenna_b.1.wrapping_add_signed(diff.1),
-                );
-
-                if antinode_a.0 < self.size.0 && antinode_a.1 < self.size.1 {
+        for frequency in self.frequencies().values() {
+            for (&antenna_a, &antenna_b) in frequency.iter().tuple_combinations() {
+                let diff = antenna_b - antenna_a;
+                let antinode_a = antenna_a - diff;
+                let antinode_b = antenna_b + diff;
+
+                if self.grid.is_in_bounds(antinode_a) {
                     antinodes.insert(antinode_a);
                 }
-                if antinode_b.0 < self.size.0 && antinode_b.1 < self.size.1 {
+                if self.grid.is_in_bounds(antinode_b) {
                     antinodes.insert(antinode_b);
                 }
             }
@@ -76,34 +62,12 @@ impl State {
     pub fn find_antinodes_resonant(&self) -> i32 {
         let mut antinodes = HashSet::new();
 
-        for frequency in self.antennas.values() {
-            for (antenna_a, antenna_b) in frequency.iter().tuple_combinations() {
-                let diff_unscaled = (
-                    antenna_b.0 as isize - antenna_a.0 as isize,
-                    antenna_b.1 as isize - antenna_a.1 as isize,
-                );
-                let diff_gcd = gcd(diff_unscaled.0, diff_unscaled.1);
-                let diff = (diff_unscaled.0 / diff_gcd, diff_unscaled.1 / diff_gcd);
-
-                let mut i = 0;
-                let mut i_step = -1;
-                loop {
-                    let antinode = (
-                        antenna_a.0.wrapping_add_signed(i * diff.0),
-                        antenna_a.1.wrapping_add_signed(i * diff.1),
-                    );
-
-                    if antinode.0 < self.size.0 && antinode.1 < self.size.1 {
-                        antinodes.insert(antinode);
-                        i += i_step;
-                    } else {
-                        if i_step == -1 {
-                            i = 1;
-                            i_step = 1;
-                        } else {
-                            break;
-                        }
-                    }
+        for frequency in self.frequencies().values() {
+            for (&antenna_a, &antenna_b) in frequency.iter().tuple_combinations() {
+                let diff = (antenna_b - antenna_a).reduce();
+
+                for step in [diff, -diff] {
+                    antinodes.extend(self.grid.ray(antenna_a, step).map(|(idx, _)| idx));
                 }
             }
         }
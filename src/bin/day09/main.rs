@@ -1,28 +1,36 @@
-use anyhow::Result;
-use aoc2024::init;
+use aoc2024::util::stream::{digits, StreamError};
+use aoc2024::{aoc_day, AocDay};
 use itertools::Itertools;
+use std::io::BufRead;
 use std::iter::{repeat, Iterator};
 
-init!("1928", "2858");
-
-fn run(input: &str) -> Result<(String, String)> {
-    let disk_map = DiskMap::from_rle(input);
-    let a = disk_map.compact().checksum();
-    let b = disk_map.defrag().checksum();
-
-    Ok((a.to_string(), b.to_string()))
-}
+aoc_day!(DiskMap, "1928", "2858");
 
 struct DiskMap {
     data: Vec<i32>,
 }
 
+impl AocDay for DiskMap {
+    fn from(input: &str) -> Self {
+        DiskMap::from_rle(input.trim_end().chars().map(|c| c.to_digit(10).unwrap() as u8))
+    }
+
+    fn from_reader<R: BufRead>(reader: R) -> Result<Self, StreamError> {
+        itertools::process_results(digits(reader), |digits| DiskMap::from_rle(digits))
+    }
+
+    fn a(&self) -> String {
+        self.compact().checksum().to_string()
+    }
+
+    fn b(&self) -> String {
+        self.defrag().checksum().to_string()
+    }
+}
+
 impl DiskMap {
-    pub fn from_rle(input: &str) -> DiskMap {
-        let data = input
-            .trim_end()
-            .chars()
-            .map(|c| c.to_digit(10).unwrap())
+    pub fn from_rle(digits: impl Iterator<Item = u8>) -> DiskMap {
+        let data = digits
             .chunks(2)
             .into_iter()
             .enumerate()
@@ -55,7 +63,8 @@ impl DiskMap {
                 src_idx -= 1;
             }
 
-            if data[dst_idx] == -1 {
+            // Skipping trailing free blocks can move the source in front of the destination.
+            if data[dst_idx] == -1 && dst_idx < src_idx {
                 data.swap(dst_idx, src_idx);
                 src_idx -= 1;
             }
@@ -105,3 +114,14 @@ impl DiskMap {
         Self { data }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compact_trailing_free_space() {
+        let disk_map = <DiskMap as AocDay>::from("131\n");
+        assert_eq!("1", disk_map.a());
+    }
+}
@@ -12,7 +12,7 @@ struct Day10 {
 
 impl AocDay for Day10 {
     fn from(input: &str) -> Self {
-        let data = input.parse_matrix(|c| c.to_digit(10).unwrap());
+        let data = input.parse_matrix(|c| c.to_digit(10).unwrap()).unwrap();
         Day10 { trail_map: data }
     }
 
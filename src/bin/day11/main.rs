@@ -11,7 +11,7 @@ struct Day11 {
 
 impl AocDay for Day11 {
     fn from(input: &str) -> Self {
-        let stones = parse!([i64 " "])(input.trim_end());
+        let stones = parse!([i64 ws])(input);
         Day11 { stones }
     }
 
@@ -1,7 +1,6 @@
-use aoc2024::{aoc_day, AocDay};
-use regex::Regex;
+use aoc2024::util::captures::FromCaptures;
+use aoc2024::{aoc_day, from_captures, AocDay};
 use std::iter::Iterator;
-use std::sync::LazyLock;
 
 aoc_day!(Day13, "480");
 
@@ -9,80 +8,62 @@ struct Day13 {
     machines: Vec<Machine>,
 }
 
-#[derive(Copy, Clone)]
-struct Machine {
-    button_a: (i64, i64),
-    button_b: (i64, i64),
-    prize: (i64, i64),
+from_captures! {
+    #[regex = r"Button A: X\+(\d+), Y\+(\d+)\nButton B: X\+(\d+), Y\+(\d+)\nPrize: X=(\d+), Y=(\d+)"]
+    #[derive(Copy, Clone)]
+    struct Machine {
+        button_a: (i64, i64) = (1, 2),
+        button_b: (i64, i64) = (3, 4),
+        prize: (i64, i64) = (5, 6),
+    }
 }
 
-static INPUT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(
-        r"Button A: X\+(\d+), Y\+(\d+)\nButton B: X\+(\d+), Y\+(\d+)\nPrize: X=(\d+), Y=(\d+)",
-    )
-    .unwrap()
-});
-
 impl AocDay for Day13 {
     fn from(input: &str) -> Self {
-        let machines = INPUT_REGEX
-            .captures_iter(input)
-            .map(|caps| {
-                let ax = caps.get(1).unwrap().as_str().parse::<i64>().unwrap();
-                let ay = caps.get(2).unwrap().as_str().parse::<i64>().unwrap();
-                let bx = caps.get(3).unwrap().as_str().parse::<i64>().unwrap();
-                let by = caps.get(4).unwrap().as_str().parse::<i64>().unwrap();
-                let px = caps.get(5).unwrap().as_str().parse::<i64>().unwrap();
-                let py = caps.get(6).unwrap().as_str().parse::<i64>().unwrap();
-
-                Machine {
-                    button_a: (ax, ay),
-                    button_b: (bx, by),
-                    prize: (px, py),
-                }
-            })
-            .collect::<Vec<_>>();
+        let machines = Machine::extract_all(input).unwrap();
 
         Day13 { machines }
     }
 
     fn a(&self) -> String {
-        self.optimize(0).to_string()
+        self.optimize(0, 100).to_string()
     }
 
     fn b(&self) -> String {
-        self.optimize(10_000_000_000_000).to_string()
+        self.optimize(10_000_000_000_000, i64::MAX).to_string()
     }
 }
 
 impl Day13 {
-    pub fn optimize(&self, prize_offset: i64) -> i64 {
+    pub fn optimize(&self, prize_offset: i64, max_presses: i64) -> i64 {
         self.machines
             .iter()
             .map(|machine| {
                 let offset_machine = Machine {
-                    prize: (
-                        machine.prize.0 + prize_offset,
-                        machine.prize.1 + prize_offset,
-                    ),
+                    prize: (machine.prize.0 + prize_offset, machine.prize.1 + prize_offset),
                     ..*machine
                 };
-                Self::optimize_machine(&offset_machine)
+                Self::optimize_machine(&offset_machine, max_presses)
             })
             .sum()
     }
 
-    pub fn optimize_machine(machine: &Machine) -> i64 {
+    /// Returns the tokens needed to win the prize, or 0 if no number of presses in `0..=max_presses`
+    /// reaches it.
+    pub fn optimize_machine(machine: &Machine, max_presses: i64) -> i64 {
         let t = machine.button_a.1 as f64 / machine.button_a.0 as f64;
         let b = (machine.prize.1 as f64 - t * machine.prize.0 as f64)
             / (machine.button_b.1 as f64 - t * machine.button_b.0 as f64);
-        let a =
-            (machine.prize.0 as f64 - machine.button_b.0 as f64 * b) / machine.button_a.0 as f64;
+        let a = (machine.prize.0 as f64 - machine.button_b.0 as f64 * b) / machine.button_a.0 as f64;
 
         let a_rounded = a.round() as i64;
         let b_rounded = b.round() as i64;
 
-        if a_rounded * machine.button_a.0 + b_rounded * machine.button_b.0 == machine.prize.0
+        let presses = 0..=max_presses;
+
+        if presses.contains(&a_rounded)
+            && presses.contains(&b_rounded)
+            && a_rounded * machine.button_a.0 + b_rounded * machine.button_b.0 == machine.prize.0
             && a_rounded * machine.button_a.1 + b_rounded * machine.button_b.1 == machine.prize.1
         {
             3 * a_rounded + b_rounded
@@ -91,3 +72,21 @@ impl Day13 {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presses_out_of_range() {
+        let machine = |prize| Machine {
+            button_a: (2, 1),
+            button_b: (1, 2),
+            prize,
+        };
+
+        assert_eq!(0, Day13::optimize_machine(&machine((0, 9)), i64::MAX));
+        assert_eq!(0, Day13::optimize_machine(&machine((202, 101)), 100));
+        assert_eq!(303, Day13::optimize_machine(&machine((202, 101)), i64::MAX));
+    }
+}
@@ -1,48 +1,33 @@
-mod util;
-
-use crate::util::{Matrix, MatrixVec};
-use aoc2024::{aoc_day, parse, AocDay};
-use regex::Regex;
+use aoc2024::util::captures::FromCaptures;
+use aoc2024::util::grid::{Coord, Grid, Rect, TorusGrid};
+use aoc2024::util::parse::sections_n;
+use aoc2024::{aoc_day, from_captures, parse, AocDay};
 use std::iter::Iterator;
-use std::sync::LazyLock;
 
 aoc_day!(Input, "12");
 
 struct Input {
-    dim: MatrixVec,
+    dim: Coord,
     guard_rules: Vec<GuardRule>,
 }
 
-#[derive(Copy, Clone)]
-struct GuardRule {
-    pos: MatrixVec,
-    vel: MatrixVec,
+from_captures! {
+    #[regex = r"p=(\d+),(\d+) v=(-?\d+),(-?\d+)"]
+    #[derive(Copy, Clone)]
+    struct GuardRule {
+        pos: Coord = (2, 1),
+        vel: Coord = (4, 3),
+    }
 }
 
-static INPUT_REGEX: LazyLock<Regex> =
-    LazyLock::new(|| Regex::new(r"p=(\d+),(\d+) v=(-?\d+),(-?\d+)").unwrap());
-
 impl AocDay for Input {
     fn from(input: &str) -> Self {
-        let (dim_str, guard_rules_str) = input.split_once("\n\n").unwrap();
-
-        let (dim_col, dim_row) = parse!((i32 "," i32))(dim_str);
-        let dim = (dim_row, dim_col).into();
-
-        let guard_rules = INPUT_REGEX
-            .captures_iter(guard_rules_str)
-            .map(|caps| {
-                let pos_col = caps.get(1).unwrap().as_str().parse::<i32>().unwrap();
-                let pos_row = caps.get(2).unwrap().as_str().parse::<i32>().unwrap();
-                let vel_col = caps.get(3).unwrap().as_str().parse::<i32>().unwrap();
-                let vel_row = caps.get(4).unwrap().as_str().parse::<i32>().unwrap();
-
-                GuardRule {
-                    pos: (pos_row, pos_col).into(),
-                    vel: (vel_row, vel_col).into(),
-                }
-            })
-            .collect::<Vec<_>>();
+        let [dim_str, guard_rules_str] = sections_n(input).unwrap();
+
+        let (dim_col, dim_row) = parse!((i64 "," i64))(dim_str);
+        let dim = Coord(dim_row as isize, dim_col as isize);
+
+        let guard_rules = GuardRule::extract_all(guard_rules_str).unwrap();
 
         Input { dim, guard_rules }
     }
@@ -53,90 +38,42 @@ impl AocDay for Input {
 
     fn b(&self) -> String {
         self.find_tree().to_string()
-        /*let mut seconds = 8;
-
-        loop {
-            seconds += 101;
-            let matrix = self.calculate_matrix(seconds);
-            println!("{} seconds:", seconds);
-            for row_idx in 0..self.dim.row {
-                for col_idx in 0..self.dim.col {
-                    let c = if matrix[(row_idx, col_idx).into()] > 0 {
-                        '#'
-                    } else {
-                        '.'
-                    };
-                    print!("{}", c);
-                }
-                println!();
-            }
-            println!();
-
-            io::stdin().lines().next();
-        }*/
     }
 }
 
 impl Input {
-    fn calculate_safety_factor(&self, seconds: i32) -> i32 {
-        let matrix = self.calculate_matrix(seconds);
-
-        let quadrant_iters = [
-            MatrixVec::iter_row_major((0, 0).into(), (self.dim.row / 2, self.dim.col / 2).into()),
-            MatrixVec::iter_row_major(
-                (0, self.dim.col / 2 + 1).into(),
-                (self.dim.row / 2, self.dim.col).into(),
-            ),
-            MatrixVec::iter_row_major(
-                (self.dim.row / 2 + 1, 0).into(),
-                (self.dim.row, self.dim.col / 2).into(),
-            ),
-            MatrixVec::iter_row_major(
-                (self.dim.row / 2 + 1, self.dim.col / 2 + 1).into(),
-                (self.dim.row, self.dim.col).into(),
-            ),
-        ];
-
-        quadrant_iters
-            .into_iter()
-            .map(|iter| iter.map(|idx| matrix[idx]).sum::<i32>())
+    fn calculate_safety_factor(&self, seconds: isize) -> i32 {
+        let grid = self.calculate_grid(seconds);
+
+        Rect::from_dim(self.dim)
+            .quadrants()
+            .iter()
+            .map(|quadrant| quadrant.iter().map(|idx| grid[idx]).sum::<i32>())
             .product()
     }
 
-    fn find_tree(&self) -> i32 {
+    fn find_tree(&self) -> isize {
         let mut seconds = 0;
 
         loop {
             seconds += 1;
-            let matrix = self.calculate_matrix(seconds);
-
-            for row_idx in 0..self.dim.row - 2 {
-                for col_idx in 0..self.dim.col - 2 {
-                    if matrix[(row_idx, col_idx).into()] > 0 {
-                        let count = MatrixVec::iter_row_major(
-                            (row_idx, col_idx).into(),
-                            (row_idx + 3, col_idx + 3).into(),
-                        )
-                        .map(|idx| (matrix[idx] > 0) as i32)
-                        .sum::<i32>();
-
-                        if count == 9 {
-                            return seconds;
-                        }
-                    }
+            let grid = self.calculate_grid(seconds);
+
+            for idx in Rect::from_dim(self.dim - Coord(2, 2)).iter() {
+                if grid[idx] > 0 && Rect::new(idx, idx + Coord(3, 3)).iter().all(|idx| grid[idx] > 0) {
+                    return seconds;
                 }
             }
         }
     }
 
-    fn calculate_matrix(&self, seconds: i32) -> Matrix<i32> {
-        let mut matrix = Matrix::from_elem(self.dim, 0);
+    fn calculate_grid(&self, seconds: isize) -> Grid<i32> {
+        let mut grid = TorusGrid::from_elem(self.dim, 0);
 
         for guard_rule in &self.guard_rules {
-            let final_guard_pos = (guard_rule.pos + guard_rule.vel * seconds).mod_euc(self.dim);
-            matrix[final_guard_pos] += 1;
+            grid[guard_rule.pos + seconds * guard_rule.vel] += 1;
         }
 
-        matrix
+        grid.into_inner()
     }
 }
@@ -1,8 +1,7 @@
-mod util;
-
-use crate::util::*;
-use aoc2024::{aoc_day, AocDay};
-use ndarray::prelude::*;
+use aoc2024::util::grid::{CharTile, Coord, Dir, Grid};
+use aoc2024::util::parse::sections_n;
+use aoc2024::util::parse_matrix::ToMatrix;
+use aoc2024::{aoc_day, char_tile, AocDay};
 
 aoc_day!(Day15, "10092", "9021");
 
@@ -13,48 +12,44 @@ struct Day15 {
 
 #[derive(Clone)]
 struct Warehouse {
-    grid: Array2<Location>,
-    guard_pos: [usize; 2],
+    grid: Grid<Location>,
+    guard_pos: Coord,
 }
 
 type Program = Vec<Dir>;
 
-#[derive(Copy, Clone, Eq, PartialEq)]
-enum Location {
-    Empty,
-    Wall,
-    Box,
-    BoxL,
-    BoxR,
+char_tile! {
+    #[derive(Copy, Clone, Eq, PartialEq)]
+    enum Location {
+        Empty => '.',
+        Wall => '#',
+        Box => 'O',
+        BoxL => '[',
+        BoxR => ']',
+    }
 }
 
 impl AocDay for Day15 {
     fn from(input: &str) -> Self {
-        let (grid_str, program_str) = input.split_once("\n\n").unwrap();
-
-        let mut guard_pos = [0, 0];
-        let grid = parse_matrix(grid_str, |pos, c| match c {
-            '#' => Location::Wall,
-            'O' => Location::Box,
-            '@' => {
-                guard_pos = pos;
-                Location::Empty
-            }
-            '.' => Location::Empty,
-            _ => panic!(),
-        });
+        let [grid_str, program_str] = sections_n(input).unwrap();
+
+        let mut guard_pos = Coord(0, 0);
+        let grid = grid_str
+            .parse_matrix_indexed(|pos, c| match c {
+                '@' => {
+                    guard_pos = Coord::try_from(pos).unwrap();
+                    Location::Empty
+                }
+                c => Location::from_char(c).unwrap(),
+            })
+            .unwrap();
+        let grid = Grid::from(grid);
         let warehouse = Warehouse { grid, guard_pos };
 
         let program = program_str
             .trim_end()
             .chars()
-            .filter_map(|c| match c {
-                '^' => Some(Dir::U),
-                '>' => Some(Dir::R),
-                'v' => Some(Dir::D),
-                '<' => Some(Dir::L),
-                _ => None,
-            })
+            .filter_map(Dir::from_char)
             .collect::<Vec<_>>();
 
         Day15 { program, warehouse }
@@ -83,15 +78,15 @@ impl Warehouse {
                 l => [l, l],
             })
             .collect::<Vec<_>>();
-        let grid_dim = (self.grid.dim().0, self.grid.dim().1 * 2);
-        let grid = Array2::from_shape_vec(grid_dim, grid_vec).unwrap();
+        let grid_dim = Coord(self.grid.dim().0, self.grid.dim().1 * 2);
+        let grid = Grid::from_vec(grid_dim, grid_vec);
 
-        let guard_pos = [self.guard_pos[0], self.guard_pos[1] * 2];
+        let guard_pos = Coord(self.guard_pos.0, self.guard_pos.1 * 2);
 
         Warehouse { grid, guard_pos }
     }
 
-    pub fn get_box_coordinate_sum(&self) -> usize {
+    pub fn get_box_coordinate_sum(&self) -> isize {
         self.grid
             .indexed_iter()
             .map(|(idx, &elem)| {
@@ -101,7 +96,7 @@ impl Warehouse {
                     0
                 }
             })
-            .sum::<usize>()
+            .sum::<isize>()
     }
 
     pub fn run_program(&mut self, program: &Program) {
@@ -118,40 +113,36 @@ impl Warehouse {
         }
     }
 
-    fn can_move_boxes(&self, pos: Position, dir: Dir) -> bool {
+    fn can_move_boxes(&self, pos: Coord, dir: Dir) -> bool {
         let next_pos = pos + dir;
 
         match self.grid[pos] {
             Location::Empty => true,
             Location::Wall => false,
-            Location::BoxL if dir == Dir::U || dir == Dir::D => {
-                self.can_move_boxes(next_pos, dir) && self.can_move_boxes(next_pos + Dir::R, dir)
+            Location::BoxL if dir.is_vertical() => {
+                self.can_move_boxes(next_pos, dir) && self.can_move_boxes(next_pos + Dir::E, dir)
             }
-            Location::BoxR if dir == Dir::U || dir == Dir::D => {
-                self.can_move_boxes(next_pos + Dir::L, dir) && self.can_move_boxes(next_pos, dir)
+            Location::BoxR if dir.is_vertical() => {
+                self.can_move_boxes(next_pos + Dir::W, dir) && self.can_move_boxes(next_pos, dir)
             }
             _ => self.can_move_boxes(next_pos, dir),
         }
     }
 
-    fn move_boxes(&mut self, pos: Position, dir: Dir) {
+    fn move_boxes(&mut self, pos: Coord, dir: Dir) {
         match self.grid[pos] {
             Location::Empty => return,
             Location::Wall => panic!(),
-            Location::BoxL if dir == Dir::U || dir == Dir::D => {
-                self.do_move_boxes(pos + Dir::R, dir)
-            }
+            Location::BoxL if dir.is_vertical() => self.do_move_boxes(pos + Dir::E, dir),
 
-            Location::BoxR if dir == Dir::U || dir == Dir::D => {
-                self.do_move_boxes(pos + Dir::L, dir)
-            }
+            Location::BoxR if dir.is_vertical() => self.do_move_boxes(pos + Dir::W, dir),
             _ => {}
         };
 
         self.do_move_boxes(pos, dir);
     }
 
-    fn do_move_boxes(&mut self, pos: Position, dir: Dir) {
+    fn do_move_boxes(&mut self, pos: Coord, dir: Dir) {
         let next_pos = pos + dir;
 
         self.move_boxes(next_pos, dir);
@@ -159,3 +150,27 @@ impl Warehouse {
         self.grid[pos] = Location::Empty;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push_wide_box() {
+        let day = <Day15 as AocDay>::from("#######\n#.....#\n#..O..#\n#..@..#\n#######\n\n^\n");
+        let mut warehouse = day.warehouse.expand();
+
+        let before = warehouse.grid.clone();
+        warehouse.run_program(&day.program);
+
+        assert_eq!(Coord(2, 6), warehouse.guard_pos);
+        assert_eq!(
+            "############## | ##############\n\
+             ##..........## | ##....[]....## *\n\
+             ##....[]....## | ##..........## *\n\
+             ##..........## | ##..........##\n\
+             ############## | ##############\n",
+            before.diff(&warehouse.grid).render_side_by_side(Location::to_char)
+        );
+    }
+}
@@ -1,7 +1,6 @@
-use aoc2024::util::grid::{Coord, Dir, Grid};
-use aoc2024::{aoc_day, AocDay};
+use aoc2024::util::grid::{BitGrid, Coord, Dir, Grid};
+use aoc2024::{aoc_day, char_tile, AocDay};
 use std::collections::HashMap;
-use strum::EnumString;
 
 aoc_day!(Day16, "7036", "45");
 
@@ -10,21 +9,19 @@ struct Day16 {
     start_idx: Coord,
 }
 
-#[derive(Copy, Clone, Eq, PartialEq, strum::Display, EnumString)]
-enum Location {
-    #[strum(serialize = ".")]
-    Empty,
-    #[strum(serialize = "#")]
-    Wall,
-    #[strum(serialize = "S")]
-    Start,
-    #[strum(serialize = "E")]
-    End,
+char_tile! {
+    #[derive(Copy, Clone, Eq, PartialEq)]
+    enum Location {
+        Empty => '.',
+        Wall => '#',
+        Start => 'S',
+        End => 'E',
+    }
 }
 
 impl AocDay for Day16 {
     fn from(input: &str) -> Self {
-        let grid = Grid::from_str(input);
+        let grid = Grid::from_tiles(input).unwrap();
         let start_idx = grid
             .indexed_iter()
             .find(|(_, &location)| location == Location::Start)
@@ -46,7 +43,7 @@ impl AocDay for Day16 {
 struct DfsAcc {
     score_grid: Grid<[i32; 4]>,
     path: Vec<Coord>,
-    visited_grids_by_score: HashMap<i32, Grid<bool>>,
+    visited_grids_by_score: HashMap<i32, BitGrid>,
 }
 
 impl Day16 {
@@ -58,10 +55,7 @@ impl Day16 {
         };
 
         let score = self.dfs(&mut acc, 0, self.start_idx, Dir::E);
-        let count = acc.visited_grids_by_score[&score]
-            .iter()
-            .map(|b| *b as i32)
-            .sum::<i32>();
+        let count = acc.visited_grids_by_score[&score].count_ones() as i32;
         (score, count)
     }
 
@@ -71,11 +65,11 @@ impl Day16 {
                 let visited_grid = acc
                     .visited_grids_by_score
                     .entry(score)
-                    .or_insert(Grid::from_elem(self.grid.dim(), false));
+                    .or_insert(BitGrid::new(self.grid.dim()));
 
-                visited_grid[idx] = true;
+                visited_grid.set(idx, true);
                 for path_idx in &acc.path {
-                    visited_grid[*path_idx] = true;
+                    visited_grid.set(*path_idx, true);
                 }
 
                 score
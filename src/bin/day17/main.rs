@@ -1,8 +1,7 @@
-use aoc2024::{aoc_day, AocDay};
+use aoc2024::util::captures::FromCaptures;
+use aoc2024::{aoc_day, from_captures, try_parse, AocDay};
 use itertools::Itertools;
-use regex::Regex;
 use std::ops::BitXor;
-use std::sync::LazyLock;
 use strum::FromRepr;
 
 aoc_day!(Day17, "4,6,3,5,6,3,5,2,1,0", "117440");
@@ -26,29 +25,17 @@ enum Instruction {
     Cdv,
 }
 
-static INPUT_REGEX: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r"Register A: (\d+)\nRegister B: (\d+)\nRegister C: (\d+)\n\nProgram: ([\d,]+)")
-        .unwrap()
-});
+from_captures! {
+    #[regex = r"Register A: (\d+)\nRegister B: (\d+)\nRegister C: (\d+)\n\nProgram: ([\d,]+)"]
+    struct Initialization {
+        r: [u64; 3] = (1, 2, 3),
+        rom: Vec<u8> = 4 => try_parse!([u8 ","]),
+    }
+}
 
 impl AocDay for Day17 {
     fn from(input: &str) -> Self {
-        let caps = INPUT_REGEX.captures(input).unwrap();
-
-        let r = [
-            caps.get(1).unwrap().as_str().parse::<u64>().unwrap(),
-            caps.get(2).unwrap().as_str().parse::<u64>().unwrap(),
-            caps.get(3).unwrap().as_str().parse::<u64>().unwrap(),
-        ];
-
-        let rom = caps
-            .get(4)
-            .unwrap()
-            .as_str()
-            .split(',')
-            .map(|ins| ins.parse::<u8>().unwrap())
-            .collect::<Vec<_>>();
-
+        let Initialization { r, rom } = Initialization::extract(input).unwrap();
         Day17 { rom, r, ip: 0 }
     }
 
@@ -58,26 +45,33 @@ impl AocDay for Day17 {
     }
 
     fn b(&self) -> String {
+        self.find_quine(0, self.rom.len()).unwrap().to_string()
+    }
+}
+
+impl Day17 {
+    /// Returns the lowest register A that extends `ra` by `len` times three bits and makes the
+    /// program output the last `len` values of itself. Each loop outputs one value and drops the
+    /// lowest three bits of A, so the highest bits determine the last values.
+    fn find_quine(&self, ra: u64, len: usize) -> Option<u64> {
+        if len == 0 {
+            return Some(ra);
+        }
+
         let mut clone = self.clone();
-        let mut ra = 0u64;
-
-        for &ins in self.rom.iter().rev() {
-            ra <<= 3;
-            loop {
-                clone.reset([ra, self.r[1], self.r[2]]);
-                let output = clone.run();
-                if output[0] == ins {
-                    break;
+        for bits in 0..8 {
+            let next_ra = ra << 3 | bits;
+            clone.reset([next_ra, self.r[1], self.r[2]]);
+            if next_ra != 0 && clone.run() == self.rom[len - 1..] {
+                if let Some(quine) = self.find_quine(next_ra, len - 1) {
+                    return Some(quine);
                 }
-                ra += 1;
             }
         }
 
-        ra.to_string()
+        None
     }
-}
 
-impl Day17 {
     pub fn reset(&mut self, r: [u64; 3]) {
         self.r = r;
         self.ip = 0;
@@ -140,3 +134,18 @@ impl Day17 {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quine_needs_backtracking() {
+        // Always taking the lowest bits that output the next of the last values leads to a dead
+        // end, so the search has to go back and take higher ones.
+        let day = <Day17 as AocDay>::from(
+            "Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 2,4,1,0,7,5,4,3,1,7,5,5,0,3,3,0\n",
+        );
+        assert_eq!("259246498679539", day.b());
+    }
+}
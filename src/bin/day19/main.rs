@@ -1,9 +1,9 @@
-use aoc2024::{aoc_day, AocDay};
+use aoc2024::util::grid::CharTile;
+use aoc2024::util::parse::sections_n;
+use aoc2024::{aoc_day, char_tile, AocDay};
 use enum_map::EnumMap;
 use itertools::Itertools;
 use std::collections::HashMap;
-use std::str::FromStr;
-use strum::EnumString;
 
 aoc_day!(Input, "6", "16");
 
@@ -13,28 +13,24 @@ struct Input {
     patterns: Vec<Vec<Stripe>>,
 }
 
-#[derive(Copy, Clone, Eq, PartialEq, EnumString, enum_map::Enum)]
-enum Stripe {
-    #[strum(serialize = "w")]
-    White,
-    #[strum(serialize = "u")]
-    Blue,
-    #[strum(serialize = "b")]
-    Black,
-    #[strum(serialize = "r")]
-    Red,
-    #[strum(serialize = "g")]
-    Green,
+char_tile! {
+    #[derive(Copy, Clone, Eq, PartialEq, enum_map::Enum)]
+    enum Stripe {
+        White => 'w',
+        Blue => 'u',
+        Black => 'b',
+        Red => 'r',
+        Green => 'g',
+    }
 }
 
 impl AocDay for Input {
     fn from(input: &str) -> Self {
         fn to_stripe(c: char) -> Stripe {
-            let mut buf = [0u8; 1];
-            Stripe::from_str(c.encode_utf8(&mut buf)).unwrap()
+            Stripe::from_char(c).unwrap()
         }
 
-        let (towels_str, patterns_str) = input.trim_end().split_once("\n\n").unwrap();
+        let [towels_str, patterns_str] = sections_n(input).unwrap();
         let towels = towels_str
             .split(", ")
             .map(|towel_str| towel_str.chars().map(to_stripe).collect_vec())
@@ -1,10 +1,9 @@
-use aoc2024::util::grid::Coord;
-use aoc2024::{aoc_day, AocDay};
+use aoc2024::util::grid::{CharTile, Coord};
+use aoc2024::{aoc_day, char_tile, AocDay};
 use arrayvec::ArrayVec;
 use itertools::Itertools;
 use std::collections::HashMap;
 use std::str::FromStr;
-use strum::EnumString;
 
 aoc_day!(Input, "126384");
 
@@ -16,8 +15,7 @@ struct Input {
 impl AocDay for Input {
     fn from(input: &str) -> Self {
         fn to_numeric_button(c: char) -> NumericButton {
-            let mut buf = [0u8; 1];
-            NumericButton::from_str(c.encode_utf8(&mut buf)).unwrap()
+            NumericButton::from_char(c).unwrap()
         }
 
         let codes = input
@@ -48,46 +46,34 @@ impl AocDay for Input {
     }
 }
 
-#[derive(Copy, Clone, Eq, PartialEq, Default, Hash, strum::Display, EnumString)]
-enum NumericButton {
-    #[strum(serialize = "7")]
-    Digit7,
-    #[strum(serialize = "8")]
-    Digit8,
-    #[strum(serialize = "9")]
-    Digit9,
-    #[strum(serialize = "4")]
-    Digit4,
-    #[strum(serialize = "5")]
-    Digit5,
-    #[strum(serialize = "6")]
-    Digit6,
-    #[strum(serialize = "1")]
-    Digit1,
-    #[strum(serialize = "2")]
-    Digit2,
-    #[strum(serialize = "3")]
-    Digit3,
-    #[strum(serialize = "0")]
-    Digit0 = 10,
-    #[strum(serialize = "A")]
-    #[default]
-    Accept,
+char_tile! {
+    #[derive(Copy, Clone, Eq, PartialEq, Default, Hash)]
+    enum NumericButton {
+        Digit7 => '7',
+        Digit8 => '8',
+        Digit9 => '9',
+        Digit4 => '4',
+        Digit5 => '5',
+        Digit6 => '6',
+        Digit1 => '1',
+        Digit2 => '2',
+        Digit3 => '3',
+        Digit0 = 10 => '0',
+        #[default]
+        Accept => 'A',
+    }
 }
 
-#[derive(Copy, Clone, Eq, PartialEq, Default, Hash, strum::Display, EnumString, Debug)]
-enum DirectionalButton {
-    #[strum(serialize = "^")]
-    Up = 1,
-    #[strum(serialize = "A")]
-    #[default]
-    Accept,
-    #[strum(serialize = "<")]
-    Left,
-    #[strum(serialize = "v")]
-    Down,
-    #[strum(serialize = ">")]
-    Right,
+char_tile! {
+    #[derive(Copy, Clone, Eq, PartialEq, Default, Hash, Debug)]
+    enum DirectionalButton {
+        Up = 1 => '^',
+        #[default]
+        Accept => 'A',
+        Left => '<',
+        Down => 'v',
+        Right => '>',
+    }
 }
 
 impl NumericButton {
@@ -214,13 +200,15 @@ impl Input {
 
     fn expand<T: Button + Copy, const N: usize>(
         sequences: HashMap<ArrayVec<T, N>, u64>,
-    ) -> HashMap<ArrayVec<DirectionalButton, 5>, u64> {
+    ) -> HashMap<ArrayVec<DirectionalButton, 6>, u64> {
         let mut expanded_sequences = HashMap::new();
         let mut last_button = T::default();
 
         for (&ref sequence, &sequence_count) in sequences.iter() {
             for &button in sequence {
-                let mut expanded_sequence = ArrayVec::<_, 5>::new();
+                // Between opposite corners of the numeric keypad, e.g. from A to 7, it takes five
+                // moves and the press of A.
+                let mut expanded_sequence = ArrayVec::<_, 6>::new();
 
                 let last_button_pos = last_button.pos();
                 let button_pos = button.pos();
@@ -309,7 +297,7 @@ impl Input {
     }*/
 
     fn push_directions(
-        sequence: &mut ArrayVec<DirectionalButton, 5>,
+        sequence: &mut ArrayVec<DirectionalButton, 6>,
         button: DirectionalButton,
         greater: isize,
         less: isize,
@@ -321,3 +309,15 @@ impl Input {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_far_corner() {
+        // From A to 7 takes the longest sequence of moves on the numeric keypad.
+        let input = <Input as AocDay>::from("700A\n");
+        assert_eq!("43400", input.a());
+    }
+}
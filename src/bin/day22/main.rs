@@ -1,5 +1,7 @@
-use aoc2024::{aoc_day, AocDay};
+use aoc2024::util::stream::{parse_lines, StreamError};
+use aoc2024::{aoc_day, parse, try_parse, AocDay};
 use itertools::Itertools;
+use std::io::BufRead;
 use std::ops::BitXor;
 
 aoc_day!(Input, "37327623", "23");
@@ -13,14 +15,15 @@ struct Input {
 
 impl AocDay for Input {
     fn from(input: &str) -> Self {
-        let seeds = input
-            .trim_end()
-            .split("\n")
-            .map(|seed_str| seed_str.parse::<i64>().unwrap())
-            .collect_vec();
+        let seeds = parse!([i64 lines])(input);
         Input { seeds }
     }
 
+    fn from_reader<R: BufRead>(reader: R) -> Result<Self, StreamError> {
+        let seeds = parse_lines(reader, try_parse!(i64)).collect::<Result<_, _>>()?;
+        Ok(Input { seeds })
+    }
+
     fn a(&self) -> String {
         self.get_number_sum().to_string()
     }
@@ -74,7 +77,7 @@ impl Input {
                     + (changes[2] as usize) * 19
                     + (changes[3] as usize);
                 if !visited[idx] {
-                    prices[idx] += seeds_prices[seed_idx][changes_idx+1] as i64;
+                    prices[idx] += seeds_prices[seed_idx][changes_idx + 1] as i64;
                     visited[idx] = true;
                 }
             }
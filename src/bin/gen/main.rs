@@ -0,0 +1,64 @@
+use anyhow::{anyhow, bail, Context, Result};
+use aoc2024::generator::{self, Rng, GENERATORS};
+use std::env;
+use std::fs;
+use std::path::Path;
+
+const USAGE: &str = "usage: gen <day> [seed] [size] [out_dir]";
+
+/// Writes a generated input to stdout and its known answers to stderr. With `out_dir`, writes
+/// them to `input.txt` and `answers.txt` there instead; the input can then be solved with
+/// `cargo run --release --bin dayNN -- <out_dir>/input.txt`.
+fn main() -> Result<()> {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    if args.is_empty() {
+        eprintln!("{}\n\ngenerators:", USAGE);
+        for generator in GENERATORS {
+            eprintln!(
+                "  day {:02}: size = {} (default {})",
+                generator.day, generator.size, generator.default_size
+            );
+        }
+        return Ok(());
+    }
+
+    let day = args[0].trim_start_matches("day").parse::<u32>().context(USAGE)?;
+    let generator = generator::find(day).ok_or_else(|| anyhow!("no generator for day {}", day))?;
+    let seed = args
+        .get(1)
+        .map(|s| s.parse::<u64>())
+        .transpose()
+        .context(USAGE)?
+        .unwrap_or(0);
+    let size = args
+        .get(2)
+        .map(|s| s.parse::<usize>())
+        .transpose()
+        .context(USAGE)?
+        .unwrap_or(generator.default_size);
+    if args.len() > 4 {
+        bail!(USAGE);
+    }
+
+    let generated = (generator.generate)(&mut Rng::new(seed), size);
+    let answers = format!(
+        "A: {}\nB: {}\n",
+        generated.a.as_deref().unwrap_or("?"),
+        generated.b.as_deref().unwrap_or("?")
+    );
+
+    match args.get(3) {
+        Some(out_dir) => {
+            let out_dir = Path::new(out_dir);
+            fs::create_dir_all(out_dir)?;
+            fs::write(out_dir.join("input.txt"), &generated.input)?;
+            fs::write(out_dir.join("answers.txt"), &answers)?;
+        }
+        None => {
+            print!("{}", generated.input);
+            eprint!("{}", answers);
+        }
+    }
+
+    Ok(())
+}
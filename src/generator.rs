@@ -0,0 +1,205 @@
+mod day01;
+mod day02;
+mod day03;
+mod day04;
+mod day05;
+mod day06;
+mod day07;
+mod day08;
+mod day09;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod maze;
+mod rng;
+
+pub use maze::{maze_path, perfect_maze};
+pub use rng::Rng;
+
+/// A generated puzzle input together with the answers that are known for it.
+#[derive(Clone, Debug, Default)]
+pub struct Generated {
+    pub input: String,
+    pub a: Option<String>,
+    pub b: Option<String>,
+}
+
+/// Produces random valid inputs for one day.
+pub struct Generator {
+    pub day: u32,
+    /// What the `size` parameter controls.
+    pub size: &'static str,
+    pub default_size: usize,
+    pub generate: fn(&mut Rng, usize) -> Generated,
+}
+
+pub const GENERATORS: &[Generator] = &[
+    Generator {
+        day: 1,
+        size: "number of lines",
+        default_size: 1000,
+        generate: day01::generate,
+    },
+    Generator {
+        day: 2,
+        size: "number of reports",
+        default_size: 1000,
+        generate: day02::generate,
+    },
+    Generator {
+        day: 3,
+        size: "number of instructions",
+        default_size: 800,
+        generate: day03::generate,
+    },
+    Generator {
+        day: 4,
+        size: "grid width and height",
+        default_size: 140,
+        generate: day04::generate,
+    },
+    Generator {
+        day: 5,
+        size: "number of updates",
+        default_size: 200,
+        generate: day05::generate,
+    },
+    Generator {
+        day: 6,
+        size: "lab width and height",
+        default_size: 130,
+        generate: day06::generate,
+    },
+    Generator {
+        day: 7,
+        size: "number of equations",
+        default_size: 850,
+        generate: day07::generate,
+    },
+    Generator {
+        day: 8,
+        size: "map width and height",
+        default_size: 50,
+        generate: day08::generate,
+    },
+    Generator {
+        day: 9,
+        size: "number of files",
+        default_size: 10_000,
+        generate: day09::generate,
+    },
+    Generator {
+        day: 10,
+        size: "map width and height",
+        default_size: 50,
+        generate: day10::generate,
+    },
+    Generator {
+        day: 11,
+        size: "number of stones",
+        default_size: 8,
+        generate: day11::generate,
+    },
+    Generator {
+        day: 12,
+        size: "garden width and height",
+        default_size: 140,
+        generate: day12::generate,
+    },
+    Generator {
+        day: 13,
+        size: "number of claw machines",
+        default_size: 320,
+        generate: day13::generate,
+    },
+    Generator {
+        day: 14,
+        size: "bathroom width",
+        default_size: 101,
+        generate: day14::generate,
+    },
+    Generator {
+        day: 15,
+        size: "warehouse width and height",
+        default_size: 50,
+        generate: day15::generate,
+    },
+    Generator {
+        day: 16,
+        size: "maze width and height in cells",
+        default_size: 70,
+        generate: day16::generate,
+    },
+    Generator {
+        day: 17,
+        size: "bits of register A",
+        default_size: 48,
+        generate: day17::generate,
+    },
+    Generator {
+        day: 18,
+        size: "maze width and height in cells",
+        default_size: 36,
+        generate: day18::generate,
+    },
+    Generator {
+        day: 19,
+        size: "number of designs",
+        default_size: 400,
+        generate: day19::generate,
+    },
+    Generator {
+        day: 20,
+        size: "maze width and height in cells",
+        default_size: 70,
+        generate: day20::generate,
+    },
+    Generator {
+        day: 21,
+        size: "number of codes",
+        default_size: 5,
+        generate: day21::generate,
+    },
+    Generator {
+        day: 22,
+        size: "number of buyers",
+        default_size: 2000,
+        generate: day22::generate,
+    },
+    Generator {
+        day: 23,
+        size: "number of computers",
+        default_size: 520,
+        generate: day23::generate,
+    },
+];
+
+pub fn find(day: u32) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|generator| generator.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generators_are_deterministic() {
+        for generator in GENERATORS {
+            let a = (generator.generate)(&mut Rng::new(5), 20);
+            let b = (generator.generate)(&mut Rng::new(5), 20);
+            assert_eq!((&a.input, &a.a, &a.b), (&b.input, &b.a, &b.b), "day {}", generator.day);
+            assert!(a.input.ends_with('\n'), "day {}", generator.day);
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use super::{Generated, Rng};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// Two columns of location IDs, with some IDs repeated so that the similarity score is not zero.
+pub fn generate(rng: &mut Rng, lines: usize) -> Generated {
+    let ids = (0..lines.max(1))
+        .map(|_| rng.between(10_000, 99_999))
+        .collect::<Vec<_>>();
+    let pick = |rng: &mut Rng| {
+        if rng.below(4) == 0 {
+            ids[rng.below(ids.len())]
+        } else {
+            rng.between(10_000, 99_999)
+        }
+    };
+
+    let mut left = (0..lines).map(|_| pick(rng)).collect::<Vec<_>>();
+    let mut right = (0..lines).map(|_| pick(rng)).collect::<Vec<_>>();
+
+    let mut input = String::new();
+    for (l, r) in left.iter().zip(&right) {
+        writeln!(input, "{}   {}", l, r).unwrap();
+    }
+
+    left.sort();
+    right.sort();
+    let distance = left.iter().zip(&right).map(|(l, r)| (l - r).abs()).sum::<i64>();
+
+    let mut counts = HashMap::new();
+    for &r in &right {
+        *counts.entry(r).or_insert(0) += 1;
+    }
+    let similarity = left.iter().map(|l| l * counts.get(l).unwrap_or(&0)).sum::<i64>();
+
+    Generated {
+        input,
+        a: Some(distance.to_string()),
+        b: Some(similarity.to_string()),
+    }
+}
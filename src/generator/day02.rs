@@ -0,0 +1,41 @@
+use super::{Generated, Rng};
+use itertools::Itertools;
+use std::fmt::Write;
+
+/// `reports` reports of five to eight levels. Each starts out safe, and some get one or two of
+/// their levels replaced, so that all of safe, dampened and unsafe reports occur.
+pub fn generate(rng: &mut Rng, reports: usize) -> Generated {
+    let mut input = String::new();
+    let mut safe = 0;
+    let mut safe_dampened = 0;
+
+    for _ in 0..reports {
+        let len = rng.between(5, 8) as usize;
+        let sign = if rng.below(2) == 0 { 1 } else { -1 };
+        let mut report = vec![rng.between(30, 70)];
+        for _ in 1..len {
+            report.push(report[report.len() - 1] + sign * rng.between(1, 3));
+        }
+
+        for _ in 0..rng.below(3) {
+            let idx = rng.below(len);
+            report[idx] = rng.between(1, 99);
+        }
+
+        safe += is_safe(&report) as usize;
+        safe_dampened += (0..len).any(|skip| is_safe(&[&report[..skip], &report[skip + 1..]].concat())) as usize;
+
+        writeln!(input, "{}", report.iter().join(" ")).unwrap();
+    }
+
+    Generated {
+        input,
+        a: Some(safe.to_string()),
+        b: Some(safe_dampened.to_string()),
+    }
+}
+
+fn is_safe(report: &[i64]) -> bool {
+    let diffs = report.windows(2).map(|pair| pair[1] - pair[0]).collect_vec();
+    diffs.iter().all(|diff| (1..=3).contains(diff)) || diffs.iter().all(|diff| (-3..=-1).contains(diff))
+}
@@ -0,0 +1,62 @@
+use super::{Generated, Rng};
+
+/// Corrupted memory with `instructions` instructions. Between valid `mul`, `do()` and `don't()`
+/// instructions are noise and near misses such as `mul(1,2]` or `mul(-1,5)`.
+///
+/// The noise never contains digits, parentheses or the letters of an instruction, so no valid
+/// instruction can form across token boundaries and the answers follow from the planted ones.
+pub fn generate(rng: &mut Rng, instructions: usize) -> Generated {
+    const NOISE: &[u8] = b"!@#$%^&*[]{}<>:;'+-_?/ ,wherexy";
+
+    let mut input = String::new();
+    let mut sum = 0;
+    let mut enabled_sum = 0;
+    let mut enabled = true;
+
+    for i in 0..instructions {
+        for _ in 0..rng.below(8) {
+            input.push(NOISE[rng.below(NOISE.len())] as char);
+        }
+        if i > 0 && rng.below(instructions / 6 + 1) == 0 {
+            input.push('\n');
+        }
+
+        let (x, y) = (rng.between(0, 999), rng.between(0, 999));
+        match rng.below(10) {
+            0 => {
+                input += "do()";
+                enabled = true;
+            }
+            1 => {
+                input += "don't()";
+                enabled = false;
+            }
+            2 => input += ["do", "don't", "do(x)", "don't[]"][rng.below(4)],
+            3 => {
+                let near_miss = match rng.below(6) {
+                    0 => format!("mul({},{}]", x, y),
+                    1 => format!("mul[{},{}]", x, y),
+                    2 => format!("mul ( {},{})", x, y),
+                    3 => format!("mul({}, {})", x, y),
+                    4 => format!("mul({},{}", x, y),
+                    _ => format!("mul(-{},{})", x, y),
+                };
+                input += &near_miss;
+            }
+            _ => {
+                input += &format!("mul({},{})", x, y);
+                sum += x * y;
+                if enabled {
+                    enabled_sum += x * y;
+                }
+            }
+        }
+    }
+    input.push('\n');
+
+    Generated {
+        input,
+        a: Some(sum.to_string()),
+        b: Some(enabled_sum.to_string()),
+    }
+}
@@ -0,0 +1,48 @@
+use super::{Generated, Rng};
+use crate::util::grid::{Coord, Grid};
+
+/// A `size * size` word search of the letters X, M, A and S with a number of planted words in all
+/// eight directions. The answers are counted by checking every cell against every direction.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.max(4) as isize;
+    let mut grid = Grid::from_vec(
+        Coord(size, size),
+        (0..size * size).map(|_| b"XMAS"[rng.below(4)] as char).collect(),
+    );
+
+    let dirs = (-1..=1)
+        .flat_map(|dr| (-1..=1).map(move |dc| Coord(dr, dc)))
+        .filter(|&dir| dir != Coord(0, 0))
+        .collect::<Vec<_>>();
+
+    for _ in 0..size * size / 8 {
+        let idx = Coord(rng.below(size as usize) as isize, rng.below(size as usize) as isize);
+        let dir = dirs[rng.below(dirs.len())];
+        if grid.is_in_bounds(idx + dir * 3) {
+            for (i, c) in "XMAS".chars().enumerate() {
+                grid[idx + dir * i as isize] = c;
+            }
+        }
+    }
+
+    let word_at = |idx: Coord, dir: Coord, word: &str| {
+        word.chars()
+            .enumerate()
+            .all(|(i, c)| grid.get(idx + dir * i as isize) == Some(&c))
+    };
+
+    let mut words = 0;
+    let mut crosses = 0;
+    for (idx, _) in grid.indexed_iter() {
+        words += dirs.iter().filter(|&&dir| word_at(idx, dir, "XMAS")).count();
+
+        let diagonal = |dir: Coord| word_at(idx - dir, dir, "MAS") || word_at(idx - dir, dir, "SAM");
+        crosses += (diagonal(Coord(1, 1)) && diagonal(Coord(1, -1))) as usize;
+    }
+
+    Generated {
+        input: grid.to_string(),
+        a: Some(words.to_string()),
+        b: Some(crosses.to_string()),
+    }
+}
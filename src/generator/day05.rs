@@ -0,0 +1,55 @@
+use super::{Generated, Rng};
+use itertools::Itertools;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// Page ordering rules between every pair of 49 pages, following a random order of them, and
+/// `updates` updates of odd length. About half of the updates are in order, the others are
+/// shuffled, so their correct order and middle pages follow from the planted order.
+pub fn generate(rng: &mut Rng, updates: usize) -> Generated {
+    let mut pages = (10..=99).collect_vec();
+    rng.shuffle(&mut pages);
+    pages.truncate(49);
+    let rank = pages
+        .iter()
+        .enumerate()
+        .map(|(i, &page)| (page, i))
+        .collect::<HashMap<_, _>>();
+
+    let mut rules = pages.iter().tuple_combinations().collect_vec();
+    rng.shuffle(&mut rules);
+
+    let mut input = String::new();
+    for (before, after) in rules {
+        writeln!(input, "{}|{}", before, after).unwrap();
+    }
+    input.push('\n');
+
+    let mut ordered_sum = 0;
+    let mut reordered_sum = 0;
+    for _ in 0..updates {
+        let mut update = pages.clone();
+        rng.shuffle(&mut update);
+        update.truncate(2 * rng.between(2, 11) as usize + 1);
+        let mut sorted = update.clone();
+        sorted.sort_by_key(|page| rank[page]);
+
+        if rng.below(2) == 0 {
+            update = sorted.clone();
+        }
+
+        let middle = sorted[sorted.len() / 2];
+        if update == sorted {
+            ordered_sum += middle;
+        } else {
+            reordered_sum += middle;
+        }
+        writeln!(input, "{}", update.iter().join(",")).unwrap();
+    }
+
+    Generated {
+        input,
+        a: Some(ordered_sum.to_string()),
+        b: Some(reordered_sum.to_string()),
+    }
+}
@@ -0,0 +1,57 @@
+use super::{Generated, Rng};
+use crate::util::grid::{Coord, Dir, Grid};
+use std::collections::HashSet;
+
+/// A `size * size` lab with scattered obstructions and the guard facing north near the middle. The
+/// answers are found by walking the guard, once as is and once for every obstruction added on the
+/// route.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.max(4) as isize;
+    // Retry until the guard takes a while to leave, without walking in a loop to begin with.
+    let (mut grid, start, route) = loop {
+        let mut grid = Grid::from_vec(
+            Coord(size, size),
+            (0..size * size)
+                .map(|_| if rng.below(25) == 0 { '#' } else { '.' })
+                .collect(),
+        );
+        let (lo, hi) = (size as i64 / 4, size as i64 * 3 / 4);
+        let start = Coord(rng.between(lo, hi) as isize, rng.between(lo, hi) as isize);
+        grid[start] = '^';
+
+        if let Some(route) = walk(&grid, start).filter(|route| route.len() >= (size * size / 16) as usize) {
+            break (grid, start, route);
+        }
+    };
+
+    let mut loops = 0;
+    for &idx in &route {
+        if idx != start {
+            grid[idx] = '#';
+            loops += walk(&grid, start).is_none() as usize;
+            grid[idx] = '.';
+        }
+    }
+
+    Generated {
+        input: grid.to_string(),
+        a: Some(route.len().to_string()),
+        b: Some(loops.to_string()),
+    }
+}
+
+/// Returns the distinct tiles visited before leaving, or `None` if the guard walks in a loop.
+fn walk(grid: &Grid<char>, start: Coord) -> Option<HashSet<Coord>> {
+    let mut states = HashSet::new();
+    let (mut idx, mut dir) = (start, Dir::N);
+
+    while states.insert((idx, dir)) {
+        match grid.get(idx + dir) {
+            None => return Some(states.into_iter().map(|(idx, _)| idx).collect()),
+            Some('#') => dir = dir.turn(1),
+            Some(_) => idx += dir,
+        }
+    }
+
+    None
+}
@@ -0,0 +1,72 @@
+use super::{Generated, Rng};
+use itertools::Itertools;
+use std::fmt::Write;
+
+/// `equations` calibration equations of three to eight numbers. A third of the test values is
+/// computed with addition and multiplication, a third with concatenation as well, and the rest is
+/// off by one from such a value.
+///
+/// The numbers have at most 14 digits in total, so neither any combination of them nor the sum of
+/// the test values overflows an `i64`.
+pub fn generate(rng: &mut Rng, equations: usize) -> Generated {
+    let mut input = String::new();
+    let mut calibration = 0;
+    let mut calibration_concat = 0;
+
+    for _ in 0..equations {
+        let numbers = loop {
+            let numbers = (0..rng.between(3, 8))
+                .map(|_| match rng.below(4) {
+                    0 => rng.between(10, 999),
+                    _ => rng.between(1, 9),
+                })
+                .collect_vec();
+            if numbers.iter().map(|n| n.to_string().len()).sum::<usize>() <= 14 {
+                break numbers;
+            }
+        };
+
+        let kind = rng.below(3);
+        let ops = if kind == 1 { 3 } else { 2 };
+        let mut value = numbers[0];
+        for &n in &numbers[1..] {
+            value = apply(rng.below(ops), value, n);
+        }
+        if kind == 2 {
+            value += 1;
+        }
+
+        if solvable(value, numbers[0], &numbers[1..], 2) {
+            calibration += value;
+        }
+        if solvable(value, numbers[0], &numbers[1..], 3) {
+            calibration_concat += value;
+        }
+
+        writeln!(input, "{}: {}", value, numbers.iter().join(" ")).unwrap();
+    }
+
+    Generated {
+        input,
+        a: Some(calibration.to_string()),
+        b: Some(calibration_concat.to_string()),
+    }
+}
+
+fn apply(op: usize, l: i64, r: i64) -> i64 {
+    match op {
+        0 => l + r,
+        1 => l * r,
+        _ => l * 10i64.pow(r.to_string().len() as u32) + r,
+    }
+}
+
+/// Tries the first `ops` operators between `acc` and the remaining numbers from left to right.
+/// No operator makes the value smaller, so branches that exceed the target are dropped.
+fn solvable(target: i64, acc: i64, numbers: &[i64], ops: usize) -> bool {
+    match numbers.split_first() {
+        _ if acc > target => false,
+        None => acc == target,
+        Some((&n, rest)) => (0..ops).any(|op| solvable(target, apply(op, acc, n), rest, ops)),
+    }
+}
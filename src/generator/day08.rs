@@ -0,0 +1,42 @@
+use super::{Generated, Rng};
+use crate::util::grid::{Coord, Grid};
+use itertools::Itertools;
+
+/// A `size * size` map with three or four antennas for each of a number of frequencies. The
+/// answers are counted by checking every tile against every pair of antennas.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+    let size = size.max(2) as isize;
+    let mut grid = Grid::from_elem(Coord(size, size), '.');
+    let mut tiles = grid.indices().collect_vec();
+    rng.shuffle(&mut tiles);
+
+    let mut pairs = vec![];
+    let mut tiles = tiles.into_iter();
+    for &frequency in FREQUENCIES.iter().take((size * size / 64).max(1) as usize) {
+        let antennas = tiles.by_ref().take(rng.between(3, 4) as usize).collect_vec();
+        for &idx in &antennas {
+            grid[idx] = frequency as char;
+        }
+        pairs.extend(antennas.into_iter().tuple_combinations::<(_, _)>());
+    }
+
+    let mut antinodes = 0;
+    let mut resonant_antinodes = 0;
+    for idx in grid.indices() {
+        antinodes += pairs
+            .iter()
+            .any(|&(a, b)| idx - a == (idx - b) * 2 || idx - b == (idx - a) * 2) as usize;
+        resonant_antinodes += pairs.iter().any(|&(a, b)| {
+            let (u, v) = (idx - a, b - a);
+            u.0 * v.1 == u.1 * v.0
+        }) as usize;
+    }
+
+    Generated {
+        input: grid.to_string(),
+        a: Some(antinodes.to_string()),
+        b: Some(resonant_antinodes.to_string()),
+    }
+}
@@ -0,0 +1,121 @@
+use super::{Generated, Rng};
+
+/// A disk map of `files` files with random file and gap lengths.
+///
+/// The answers are computed on file spans rather than blocks, so they serve as an independent
+/// reference for the block-based solver.
+pub fn generate(rng: &mut Rng, files: usize) -> Generated {
+    let mut input = String::with_capacity(files * 2 + 1);
+    let mut spans = vec![];
+    let mut gaps = vec![];
+    let mut pos = 0;
+
+    for id in 0..files {
+        let len = rng.between(1, 9) as usize;
+        input.push(char::from_digit(len as u32, 10).unwrap());
+        spans.push((id, pos, len));
+        pos += len;
+
+        if id + 1 < files {
+            let gap = rng.below(10);
+            input.push(char::from_digit(gap as u32, 10).unwrap());
+            gaps.push((pos, gap));
+            pos += gap;
+        }
+    }
+    input.push('\n');
+
+    Generated {
+        input,
+        a: Some(checksum(&compact(&spans)).to_string()),
+        b: Some(checksum(&defrag(&spans, gaps)).to_string()),
+    }
+}
+
+/// Moves single blocks from the end into the leftmost gaps.
+fn compact(spans: &[(usize, usize, usize)]) -> Vec<(usize, usize, usize)> {
+    let mut left = spans.iter().copied().collect::<std::collections::VecDeque<_>>();
+    let mut compacted = vec![];
+    let mut pos = 0;
+
+    while let Some((id, start, len)) = left.pop_front() {
+        if start > pos {
+            // Fill the gap in front of this file from the last file.
+            let Some((last_id, last_start, last_len)) = left.pop_back() else {
+                left.push_front((id, pos, len));
+                continue;
+            };
+            let moved = last_len.min(start - pos);
+            compacted.push((last_id, pos, moved));
+            pos += moved;
+            if moved < last_len {
+                left.push_back((last_id, last_start, last_len - moved));
+            }
+            left.push_front((id, start, len));
+        } else {
+            compacted.push((id, pos, len));
+            pos += len;
+        }
+    }
+
+    compacted
+}
+
+/// Moves whole files, highest ID first, into the leftmost gap that fits them.
+fn defrag(spans: &[(usize, usize, usize)], mut gaps: Vec<(usize, usize)>) -> Vec<(usize, usize, usize)> {
+    let mut spans = spans.to_vec();
+
+    for span in spans.iter_mut().rev() {
+        let (_, start, len) = *span;
+        if let Some(gap) = gaps.iter_mut().take_while(|gap| gap.0 < start).find(|gap| gap.1 >= len) {
+            span.1 = gap.0;
+            gap.0 += len;
+            gap.1 -= len;
+        }
+    }
+
+    spans
+}
+
+fn checksum(spans: &[(usize, usize, usize)]) -> usize {
+    spans
+        .iter()
+        .map(|&(id, start, len)| id * (start..start + len).sum::<usize>())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference() {
+        // 2333133121414131402, the example of the puzzle
+        let spans = [
+            (0, 0, 2),
+            (1, 5, 3),
+            (2, 11, 1),
+            (3, 15, 3),
+            (4, 19, 2),
+            (5, 22, 4),
+            (6, 27, 4),
+            (7, 32, 3),
+            (8, 36, 4),
+            (9, 40, 2),
+        ];
+        let gaps = vec![
+            (2, 3),
+            (8, 3),
+            (12, 3),
+            (18, 1),
+            (21, 1),
+            (26, 1),
+            (31, 1),
+            (35, 1),
+            (40, 0),
+        ];
+
+        assert_eq!(1928, checksum(&compact(&spans)));
+        assert_eq!(2858, checksum(&defrag(&spans, gaps)));
+    }
+}
@@ -0,0 +1,68 @@
+use super::{Generated, Rng};
+use crate::util::grid::{Coord, Dir, Grid};
+use strum::IntoEnumIterator;
+
+/// A `size * size` topographic map of hills that rise by one per step towards their peaks, with
+/// one in ten tiles replaced by a random height to break up some of the trails. The answers are
+/// counted by following every trail from every trailhead.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.max(2) as isize;
+    let peaks = (0..(size * size / 60).max(1))
+        .map(|_| Coord(rng.below(size as usize) as isize, rng.below(size as usize) as isize))
+        .collect::<Vec<_>>();
+
+    let grid = Grid::from_vec(
+        Coord(size, size),
+        (0..size * size)
+            .map(|i| {
+                let idx = Coord(i / size, i % size);
+                if rng.below(10) == 0 {
+                    rng.below(10) as u32
+                } else {
+                    9 - peaks.iter().map(|&peak| idx.manhattan(peak)).min().unwrap().min(9) as u32
+                }
+            })
+            .collect(),
+    );
+
+    let mut score = 0;
+    let mut rating = 0;
+    for (idx, &height) in grid.indexed_iter() {
+        if height == 0 {
+            let mut ends = vec![];
+            follow(&grid, idx, &mut ends);
+            rating += ends.len();
+            ends.sort();
+            ends.dedup();
+            score += ends.len();
+        }
+    }
+
+    let mut input = String::new();
+    for (idx, height) in grid.indexed_iter() {
+        input.push(char::from_digit(*height, 10).unwrap());
+        if idx.1 == size - 1 {
+            input.push('\n');
+        }
+    }
+
+    Generated {
+        input,
+        a: Some(score.to_string()),
+        b: Some(rating.to_string()),
+    }
+}
+
+/// Collects the end of every distinct trail from `idx`, once per trail.
+fn follow(grid: &Grid<u32>, idx: Coord, ends: &mut Vec<Coord>) {
+    if grid[idx] == 9 {
+        ends.push(idx);
+        return;
+    }
+
+    for dir in Dir::iter() {
+        if grid.get(idx + dir) == Some(&(grid[idx] + 1)) {
+            follow(grid, idx + dir, ends);
+        }
+    }
+}
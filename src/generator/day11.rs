@@ -0,0 +1,45 @@
+use super::{Generated, Rng};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+/// A row of `stones` stones with up to seven digits. The answers are counted stone by stone with a
+/// memoized recursion over the number of blinks left.
+pub fn generate(rng: &mut Rng, stones: usize) -> Generated {
+    let stones = (0..stones.max(1))
+        .map(|_| {
+            let digits = rng.between(1, 7) as u32;
+            rng.between(0, 10i64.pow(digits) - 1) as u64
+        })
+        .collect_vec();
+
+    let mut memo = HashMap::new();
+    let mut total = |blinks| stones.iter().map(|&stone| count(stone, blinks, &mut memo)).sum::<u64>();
+
+    Generated {
+        input: format!("{}\n", stones.iter().join(" ")),
+        a: Some(total(25).to_string()),
+        b: Some(total(75).to_string()),
+    }
+}
+
+fn count(stone: u64, blinks: u32, memo: &mut HashMap<(u64, u32), u64>) -> u64 {
+    if blinks == 0 {
+        return 1;
+    }
+    if let Some(&n) = memo.get(&(stone, blinks)) {
+        return n;
+    }
+
+    let digits = stone.to_string();
+    let n = if stone == 0 {
+        count(1, blinks - 1, memo)
+    } else if digits.len().is_multiple_of(2) {
+        let (left, right) = digits.split_at(digits.len() / 2);
+        count(left.parse().unwrap(), blinks - 1, memo) + count(right.parse().unwrap(), blinks - 1, memo)
+    } else {
+        count(stone * 2024, blinks - 1, memo)
+    };
+
+    memo.insert((stone, blinks), n);
+    n
+}
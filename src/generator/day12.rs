@@ -0,0 +1,66 @@
+use super::{Generated, Rng};
+use crate::util::grid::{Coord, Dir, Grid};
+use strum::IntoEnumIterator;
+
+/// A `size * size` garden of random plants that is smoothed by repeatedly copying a plant to a
+/// random neighbour, which grows regions of all shapes, including ones with holes.
+///
+/// The perimeter counts every fence segment. The sides count only the segments that do not
+/// continue a fence of the same region and orientation from their left-hand neighbour.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.max(1) as isize;
+    let mut grid = Grid::from_vec(
+        Coord(size, size),
+        (0..size * size).map(|_| (b'A' + rng.below(8) as u8) as char).collect(),
+    );
+
+    for _ in 0..size * size * 3 {
+        let idx = Coord(rng.below(size as usize) as isize, rng.below(size as usize) as isize);
+        let next_idx = idx + Dir::from_repr(rng.below(4)).unwrap();
+        if grid.is_in_bounds(next_idx) {
+            grid[next_idx] = grid[idx];
+        }
+    }
+
+    let mut region = Grid::from_elem(grid.dim(), usize::MAX);
+    let mut regions = 0;
+    for start in grid.indices() {
+        if region[start] == usize::MAX {
+            let mut stack = vec![start];
+            region[start] = regions;
+            while let Some(idx) = stack.pop() {
+                for dir in Dir::iter() {
+                    let next_idx = idx + dir;
+                    if grid.get(next_idx) == Some(&grid[idx]) && region[next_idx] == usize::MAX {
+                        region[next_idx] = regions;
+                        stack.push(next_idx);
+                    }
+                }
+            }
+            regions += 1;
+        }
+    }
+
+    let mut areas = vec![0; regions];
+    let mut perimeters = vec![0; regions];
+    let mut sides = vec![0; regions];
+    let fence = |idx: Coord, dir: Dir, r: usize| region.get(idx) == Some(&r) && region.get(idx + dir) != Some(&r);
+
+    for (idx, &r) in region.indexed_iter() {
+        areas[r] += 1;
+        for dir in Dir::iter() {
+            if fence(idx, dir, r) {
+                perimeters[r] += 1;
+                sides[r] += !fence(idx + dir.turn(-1), dir, r) as usize;
+            }
+        }
+    }
+
+    let price = |fences: &[usize]| (0..regions).map(|r| areas[r] * fences[r]).sum::<usize>();
+
+    Generated {
+        input: grid.to_string(),
+        a: Some(price(&perimeters).to_string()),
+        b: Some(price(&sides).to_string()),
+    }
+}
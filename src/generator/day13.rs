@@ -0,0 +1,84 @@
+use super::{Generated, Rng};
+use std::fmt::Write;
+
+const FAR: i64 = 10_000_000_000_000;
+
+/// `machines` claw machines whose buttons move the claw in linearly independent directions, so
+/// every prize can be reached by at most one combination of presses. A third of the prizes is
+/// placed at a combination of up to 100 presses of each button, a third so that they can be
+/// reached once they are moved 10000000000000 further, and the rest at random.
+///
+/// The answers solve each machine exactly with Cramer's rule.
+pub fn generate(rng: &mut Rng, machines: usize) -> Generated {
+    let mut input = String::new();
+    let mut tokens = 0;
+    let mut tokens_far = 0;
+
+    for i in 0..machines {
+        let (a, b) = loop {
+            let a = (rng.between(10, 99), rng.between(10, 99));
+            let b = (rng.between(10, 99), rng.between(10, 99));
+            if a.0 * b.1 != a.1 * b.0 {
+                break (a, b);
+            }
+        };
+
+        let prize = loop {
+            let prize = match rng.below(3) {
+                0 => {
+                    let (presses_a, presses_b) = (rng.between(0, 100), rng.between(0, 100));
+                    (presses_a * a.0 + presses_b * b.0, presses_a * a.1 + presses_b * b.1)
+                }
+                1 => {
+                    // Round the presses that reach a random far prize, which moves it by less than
+                    // the length of a press of each button.
+                    let far = (rng.between(1000, 20_000) + FAR, rng.between(1000, 20_000) + FAR);
+                    let det = (a.0 * b.1 - a.1 * b.0) as f64;
+                    let presses_a = ((far.0 * b.1 - far.1 * b.0) as f64 / det).round() as i64;
+                    let presses_b = ((a.0 * far.1 - a.1 * far.0) as f64 / det).round() as i64;
+                    (
+                        presses_a * a.0 + presses_b * b.0 - FAR,
+                        presses_a * a.1 + presses_b * b.1 - FAR,
+                    )
+                }
+                _ => (rng.between(1000, 20_000), rng.between(1000, 20_000)),
+            };
+            if prize.0 >= 0 && prize.1 >= 0 {
+                break prize;
+            }
+        };
+
+        tokens += solve(a, b, prize)
+            .filter(|&(na, nb)| na <= 100 && nb <= 100)
+            .map_or(0, cost);
+        tokens_far += solve(a, b, (prize.0 + FAR, prize.1 + FAR)).map_or(0, cost);
+
+        if i > 0 {
+            input.push('\n');
+        }
+        writeln!(input, "Button A: X+{}, Y+{}", a.0, a.1).unwrap();
+        writeln!(input, "Button B: X+{}, Y+{}", b.0, b.1).unwrap();
+        writeln!(input, "Prize: X={}, Y={}", prize.0, prize.1).unwrap();
+    }
+
+    Generated {
+        input,
+        a: Some(tokens.to_string()),
+        b: Some(tokens_far.to_string()),
+    }
+}
+
+/// Returns the non-negative presses of both buttons that reach `prize`, if there are any.
+fn solve(a: (i64, i64), b: (i64, i64), prize: (i64, i64)) -> Option<(i64, i64)> {
+    let det = a.0 * b.1 - a.1 * b.0;
+    let det_a = prize.0 * b.1 - prize.1 * b.0;
+    let det_b = a.0 * prize.1 - a.1 * prize.0;
+
+    (det_a % det == 0 && det_b % det == 0)
+        .then(|| (det_a / det, det_b / det))
+        .filter(|&(na, nb)| na >= 0 && nb >= 0)
+}
+
+fn cost((presses_a, presses_b): (i64, i64)) -> i64 {
+    3 * presses_a + presses_b
+}
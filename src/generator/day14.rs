@@ -0,0 +1,80 @@
+use super::{Generated, Rng};
+use crate::util::grid::{Coord, Grid};
+use std::fmt::Write;
+
+/// A bathroom `size` tiles wide and two tiles higher, with a robot for about every twentieth tile,
+/// plus robots that form a Christmas tree after a random number of seconds.
+///
+/// The tree is the first arrangement with a filled 3x3 block of robots. The generator retries until
+/// no such block appears earlier, so the second it is planted at is the answer.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let width = size.max(21) as isize | 1;
+    let dim = Coord(width + 2, width);
+    let period = dim.0 * dim.1;
+
+    let (seconds, mut robots) = loop {
+        let seconds = rng.between(1, period as i64 - 1) as isize;
+        let top = Coord(
+            rng.below((dim.0 - 9) as usize) as isize,
+            rng.below((dim.1 - 15) as usize) as isize,
+        );
+        let tree = (0..8)
+            .flat_map(|row| (7 - row..=7 + row).map(move |col| Coord(row, col)))
+            .chain([Coord(8, 6), Coord(8, 7), Coord(8, 8)]);
+
+        let mut robots = vec![];
+        for pos in tree.map(|offset| top + offset) {
+            let vel = random_velocity(rng, dim);
+            robots.push(((pos - seconds * vel).rem_euclid(dim), vel));
+        }
+        for _ in 0..period / 20 {
+            let pos = Coord(rng.below(dim.0 as usize) as isize, rng.below(dim.1 as usize) as isize);
+            robots.push((pos, random_velocity(rng, dim)));
+        }
+
+        if (1..seconds).all(|earlier| !has_filled_block(&robots, dim, earlier)) {
+            break (seconds, robots);
+        }
+    };
+    rng.shuffle(&mut robots);
+
+    let mut input = String::new();
+    writeln!(input, "{},{}\n", dim.1, dim.0).unwrap();
+    for (pos, vel) in &robots {
+        writeln!(input, "p={},{} v={},{}", pos.1, pos.0, vel.1, vel.0).unwrap();
+    }
+
+    let mut quadrants = [0; 4];
+    for (pos, vel) in &robots {
+        let pos = (*pos + 100 * *vel).rem_euclid(dim);
+        let middle = Coord(dim.0 / 2, dim.1 / 2);
+        if pos.0 != middle.0 && pos.1 != middle.1 {
+            quadrants[(pos.0 > middle.0) as usize * 2 + (pos.1 > middle.1) as usize] += 1;
+        }
+    }
+
+    Generated {
+        input,
+        a: Some(quadrants.iter().product::<usize>().to_string()),
+        b: Some(seconds.to_string()),
+    }
+}
+
+fn random_velocity(rng: &mut Rng, dim: Coord) -> Coord {
+    Coord(
+        rng.between(1 - dim.0 as i64, dim.0 as i64 - 1) as isize,
+        rng.between(1 - dim.1 as i64, dim.1 as i64 - 1) as isize,
+    )
+}
+
+fn has_filled_block(robots: &[(Coord, Coord)], dim: Coord, seconds: isize) -> bool {
+    let mut grid = Grid::from_elem(dim, false);
+    for &(pos, vel) in robots {
+        grid[(pos + seconds * vel).rem_euclid(dim)] = true;
+    }
+
+    robots.iter().any(|&(pos, vel)| {
+        let top_left = (pos + seconds * vel).rem_euclid(dim);
+        (0..3).all(|row| (0..3).all(|col| grid.get(top_left + Coord(row, col)) == Some(&true)))
+    })
+}
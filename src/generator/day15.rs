@@ -0,0 +1,105 @@
+use super::{Generated, Rng};
+use crate::util::grid::{Coord, Dir, Grid};
+use std::collections::HashSet;
+
+/// A walled `size * size` warehouse with scattered walls and boxes, and eight random moves of the
+/// robot per tile.
+///
+/// The answers come from a simulation that collects everything a move pushes before moving it,
+/// which works the same for the narrow and the wide warehouse.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.max(3) as isize;
+    let mut grid = Grid::from_elem(Coord(size, size), '#');
+    for row in 1..size - 1 {
+        for col in 1..size - 1 {
+            grid[Coord(row, col)] = match rng.below(12) {
+                0 => '#',
+                1..=3 => 'O',
+                _ => '.',
+            };
+        }
+    }
+    let robot = Coord(
+        rng.between(1, size as i64 - 2) as isize,
+        rng.between(1, size as i64 - 2) as isize,
+    );
+    grid[robot] = '@';
+
+    let moves = (0..size * size * 8)
+        .map(|_| Dir::from_repr(rng.below(4)).unwrap())
+        .collect::<Vec<_>>();
+
+    let mut input = grid.to_string();
+    input.push('\n');
+    for line in moves.chunks(70) {
+        input.extend(line.iter().map(|dir| dir.to_arrow()));
+        input.push('\n');
+    }
+
+    let wide = Grid::from_vec(
+        Coord(size, 2 * size),
+        grid.iter()
+            .flat_map(|&c| match c {
+                'O' => ['[', ']'],
+                '@' => ['@', '.'],
+                c => [c, c],
+            })
+            .collect(),
+    );
+
+    Generated {
+        input,
+        a: Some(run(grid, robot, &moves).to_string()),
+        b: Some(run(wide, Coord(robot.0, 2 * robot.1), &moves).to_string()),
+    }
+}
+
+/// Moves the robot and returns the sum of the GPS coordinates of all boxes.
+fn run(mut grid: Grid<char>, mut robot: Coord, moves: &[Dir]) -> isize {
+    for &dir in moves {
+        let mut pushed = vec![robot];
+        let mut seen = HashSet::from([robot]);
+        let mut blocked = false;
+        let mut i = 0;
+
+        while i < pushed.len() && !blocked {
+            let next_idx = pushed[i] + dir;
+            i += 1;
+
+            let mut push = |idx: Coord| {
+                if seen.insert(idx) {
+                    pushed.push(idx);
+                }
+            };
+            match grid[next_idx] {
+                '#' => blocked = true,
+                'O' => push(next_idx),
+                '[' => {
+                    push(next_idx);
+                    push(next_idx + Dir::E);
+                }
+                ']' => {
+                    push(next_idx);
+                    push(next_idx + Dir::W);
+                }
+                _ => {}
+            }
+        }
+
+        if !blocked {
+            let moved = pushed.iter().map(|&idx| (idx + dir, grid[idx])).collect::<Vec<_>>();
+            for &idx in &pushed {
+                grid[idx] = '.';
+            }
+            for (idx, c) in moved {
+                grid[idx] = c;
+            }
+            robot += dir;
+        }
+    }
+
+    grid.indexed_iter()
+        .filter(|&(_, &c)| c == 'O' || c == '[')
+        .map(|(idx, _)| 100 * idx.0 + idx.1)
+        .sum()
+}
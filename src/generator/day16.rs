@@ -0,0 +1,43 @@
+use super::{maze_path, perfect_maze, Generated, Rng};
+use crate::util::grid::{Coord, Dir, Grid};
+
+/// A walled perfect maze of `size * size` cells with the start in the bottom left and the end in
+/// the top right corner. The only path between them is the best one, so both answers follow
+/// from it.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let cells = size.max(2) as isize;
+    let maze = perfect_maze(rng, Coord(cells, cells));
+    let start = Coord(2 * cells - 2, 0);
+    let end = Coord(0, 2 * cells - 2);
+    let path = maze_path(&maze, start, end);
+
+    let mut dir = Dir::E;
+    let mut score = 0;
+    for step in path.windows(2) {
+        let next_dir = Dir::between(step[0], step[1]).unwrap();
+        score += 1 + if next_dir == dir {
+            0
+        } else if next_dir == dir.opposite() {
+            2000
+        } else {
+            1000
+        };
+        dir = next_dir;
+    }
+
+    let offset = Coord(1, 1);
+    let mut grid = Grid::from_elem(maze.dim() + Coord(2, 2), '#');
+    for (idx, &open) in maze.indexed_iter() {
+        if open {
+            grid[idx + offset] = '.';
+        }
+    }
+    grid[start + offset] = 'S';
+    grid[end + offset] = 'E';
+
+    Generated {
+        input: grid.to_string(),
+        a: Some(score.to_string()),
+        b: Some(path.len().to_string()),
+    }
+}
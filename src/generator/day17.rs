@@ -0,0 +1,87 @@
+use super::{Generated, Rng};
+use itertools::Itertools;
+
+/// A program of the shape of the real inputs, which outputs one value per three bits of register A:
+/// it xors the lowest bits of A with constants and with a shifted copy of A, and drops those bits
+/// until A is zero. Register A holds a random number of `size` bits.
+///
+/// The constants are redrawn until the program can output itself. The lowest such A is found by a
+/// depth-first search over A three bits at a time, starting from the highest bits, which determine
+/// the last output values.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let bits = size.clamp(1, 48) as u32;
+
+    let (program, quine) = loop {
+        let mut body = vec![[1, rng.below(8) as u8], [4, rng.below(8) as u8]];
+        rng.shuffle(&mut body);
+        body.push([5, 5]);
+        body.insert(rng.below(body.len() + 1), [0, 3]);
+
+        let program = [[2, 4], [1, rng.below(8) as u8], [7, 5]]
+            .into_iter()
+            .chain(body)
+            .chain([[3, 0]])
+            .flatten()
+            .collect_vec();
+
+        if let Some(quine) = find_quine(&program, 0, program.len()) {
+            break (program, quine);
+        }
+    };
+
+    let a = rng.between(1 << (bits - 1), (1 << bits) - 1) as u64;
+
+    Generated {
+        input: format!(
+            "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+            a,
+            program.iter().join(",")
+        ),
+        a: Some(run(&program, a).iter().join(",")),
+        b: Some(quine.to_string()),
+    }
+}
+
+/// Returns the lowest A that extends `prefix` by `len` times three bits and makes the program
+/// output its last `len` values.
+fn find_quine(program: &[u8], prefix: u64, len: usize) -> Option<u64> {
+    if len == 0 {
+        return Some(prefix);
+    }
+
+    (0..8)
+        .map(|bits| prefix << 3 | bits)
+        .filter(|&a| a != 0 && run(program, a) == program[len - 1..])
+        .find_map(|a| find_quine(program, a, len - 1))
+}
+
+fn run(program: &[u8], a: u64) -> Vec<u8> {
+    let mut r = [a, 0, 0];
+    let mut ip = 0;
+    let mut output = vec![];
+
+    while ip + 1 < program.len() {
+        let (opcode, literal) = (program[ip], program[ip + 1] as u64);
+        // 7 is not a valid combo operand and only appears as a literal one.
+        let combo = match literal {
+            0..=3 => literal,
+            4..=6 => r[literal as usize - 4],
+            _ => 0,
+        };
+        ip += 2;
+
+        match opcode {
+            0 => r[0] >>= combo,
+            1 => r[1] ^= literal,
+            2 => r[1] = combo % 8,
+            3 if r[0] != 0 => ip = literal as usize,
+            3 => {}
+            4 => r[1] ^= r[2],
+            5 => output.push((combo % 8) as u8),
+            6 => r[1] = r[0] >> combo,
+            _ => r[2] = r[0] >> combo,
+        }
+    }
+
+    output
+}
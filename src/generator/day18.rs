@@ -0,0 +1,37 @@
+use super::{maze_path, perfect_maze, Generated, Rng};
+use crate::util::grid::Coord;
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// A memory space whose first bytes are the walls of a perfect maze of `size * size` cells,
+/// followed by every other tile except the start and the end in random order.
+///
+/// The only path through the maze is the shortest one, and the first later byte that falls on it
+/// cuts the start off from the end.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let cells = size.max(2) as isize;
+    let maze = perfect_maze(rng, Coord(cells, cells));
+    let start = Coord(0, 0);
+    let end = maze.dim() - Coord(1, 1);
+    let path = maze_path(&maze, start, end);
+
+    let (mut walls, mut open): (Vec<_>, Vec<_>) = maze.indexed_iter().partition(|&(_, &open)| !open);
+    rng.shuffle(&mut walls);
+    rng.shuffle(&mut open);
+    open.retain(|&(idx, _)| idx != start && idx != end);
+
+    let on_path = path.iter().collect::<HashSet<_>>();
+    let blocking = open.iter().find(|(idx, _)| on_path.contains(idx)).unwrap().0;
+
+    let mut input = String::new();
+    writeln!(input, "{},{}\n", end.1, walls.len()).unwrap();
+    for (idx, _) in walls.iter().chain(&open) {
+        writeln!(input, "{},{}", idx.1, idx.0).unwrap();
+    }
+
+    Generated {
+        input,
+        a: Some((path.len() - 1).to_string()),
+        b: Some(format!("{},{}", blocking.1, blocking.0)),
+    }
+}
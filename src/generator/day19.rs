@@ -0,0 +1,65 @@
+use super::{Generated, Rng};
+use itertools::Itertools;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+const STRIPES: &[u8] = b"wubrg";
+
+/// Towels of one to eight stripes and `designs` designs of 20 to 60 stripes. Half of the designs
+/// are laid out from the towels, the others are random. No towel is a single white stripe, so
+/// random designs are often impossible.
+///
+/// The answers count the arrangements of every prefix of a design from the shorter prefixes.
+pub fn generate(rng: &mut Rng, designs: usize) -> Generated {
+    let mut towels = BTreeSet::new();
+    while towels.len() < 100 {
+        let len = rng.between(1, 8) as usize;
+        let towel = random_stripes(rng, len);
+        if towel != "w" {
+            towels.insert(towel);
+        }
+    }
+    let mut towels = towels.into_iter().collect_vec();
+    rng.shuffle(&mut towels);
+
+    let mut input = format!("{}\n\n", towels.iter().join(", "));
+    let mut possible = 0;
+    let mut arrangements = 0;
+
+    for _ in 0..designs {
+        let len = rng.between(20, 60) as usize;
+        let design = if rng.below(2) == 0 {
+            let mut design = String::new();
+            while design.len() < len {
+                design += &towels[rng.below(towels.len())];
+            }
+            design
+        } else {
+            random_stripes(rng, len)
+        };
+
+        let mut ways = vec![0u64; design.len() + 1];
+        ways[0] = 1;
+        for end in 1..=design.len() {
+            ways[end] = towels
+                .iter()
+                .filter(|towel| design[..end].ends_with(towel.as_str()))
+                .map(|towel| ways[end - towel.len()])
+                .sum();
+        }
+
+        possible += (ways[design.len()] > 0) as usize;
+        arrangements += ways[design.len()];
+        writeln!(input, "{}", design).unwrap();
+    }
+
+    Generated {
+        input,
+        a: Some(possible.to_string()),
+        b: Some(arrangements.to_string()),
+    }
+}
+
+fn random_stripes(rng: &mut Rng, len: usize) -> String {
+    (0..len).map(|_| STRIPES[rng.below(STRIPES.len())] as char).collect()
+}
@@ -0,0 +1,46 @@
+use super::{maze_path, perfect_maze, Generated, Rng};
+use crate::util::grid::{Coord, Grid};
+use std::fmt::Write;
+
+/// A race track along the path between opposite corners of a walled perfect maze of `size * size`
+/// cells, with all tiles off the path turned into walls. The minimum savings scale with the length
+/// of the track.
+///
+/// The answers check every pair of track tiles that are close enough for a cheat.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let cells = size.max(2) as isize;
+    let maze = perfect_maze(rng, Coord(cells, cells));
+    let start = Coord(0, 0);
+    let end = maze.dim() - Coord(1, 1);
+    let path = maze_path(&maze, start, end);
+
+    let offset = Coord(1, 1);
+    let mut grid = Grid::from_elem(maze.dim() + Coord(2, 2), '#');
+    for &idx in &path {
+        grid[idx + offset] = '.';
+    }
+    grid[start + offset] = 'S';
+    grid[end + offset] = 'E';
+
+    let min_savings = ((path.len() / 100).max(1), (path.len() / 20).max(1));
+    let cheats = |max_len: usize, min_saving: usize| {
+        let mut count = 0;
+        for (i, &from) in path.iter().enumerate() {
+            for (j, &to) in path.iter().enumerate().skip(i + min_saving) {
+                let len = from.manhattan(to);
+                count += ((2..=max_len).contains(&len) && j - i >= len + min_saving) as usize;
+            }
+        }
+        count
+    };
+
+    let mut input = String::new();
+    writeln!(input, "{},{}\n", min_savings.0, min_savings.1).unwrap();
+    input += &grid.to_string();
+
+    Generated {
+        input,
+        a: Some(cheats(2, min_savings.0).to_string()),
+        b: Some(cheats(20, min_savings.1).to_string()),
+    }
+}
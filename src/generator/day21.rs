@@ -0,0 +1,104 @@
+use super::{Generated, Rng};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+const NUMERIC: [&str; 4] = ["789", "456", "123", " 0A"];
+const DIRECTIONAL: [&str; 2] = [" ^A", "<v>"];
+
+/// `codes` door codes of three digits followed by A.
+///
+/// The answers try both orders of horizontal and vertical moves between any two buttons, except
+/// the one that crosses the gap, and keep the one that is cheapest for the keypads above.
+pub fn generate(rng: &mut Rng, codes: usize) -> Generated {
+    let codes = (0..codes.max(1))
+        .map(|_| format!("{:03}A", rng.below(1000)))
+        .collect::<Vec<_>>();
+
+    let mut memo = HashMap::new();
+    let mut complexity = |robots: usize| {
+        codes
+            .iter()
+            .map(|code| presses(code, &NUMERIC, robots + 1, &mut memo) * code[..3].parse::<u64>().unwrap())
+            .sum::<u64>()
+    };
+
+    let mut input = String::new();
+    for code in &codes {
+        writeln!(input, "{}", code).unwrap();
+    }
+
+    Generated {
+        input,
+        a: Some(complexity(2).to_string()),
+        b: Some(complexity(25).to_string()),
+    }
+}
+
+/// Returns the number of button presses on the outermost keypad that make the robot at `keypad`
+/// type `sequence`, with `depth` directional keypads in between.
+fn presses(sequence: &str, keypad: &[&str], depth: usize, memo: &mut HashMap<(char, char, usize), u64>) -> u64 {
+    if depth == 0 {
+        return sequence.len() as u64;
+    }
+
+    let mut from = 'A';
+    let mut total = 0;
+    for to in sequence.chars() {
+        // The keypads only share A, so the buttons tell them apart.
+        let key = (from, to, depth);
+        let cost = match memo.get(&key) {
+            Some(&cost) => cost,
+            None => {
+                let cost = moves(keypad, from, to)
+                    .iter()
+                    .map(|moves| presses(moves, &DIRECTIONAL, depth - 1, memo))
+                    .min()
+                    .unwrap();
+                memo.insert(key, cost);
+                cost
+            }
+        };
+        total += cost;
+        from = to;
+    }
+
+    total
+}
+
+/// Returns the sequences that move from `from` to `to` with all horizontal moves before or after
+/// all vertical ones without crossing the gap, each followed by a press of A.
+fn moves(keypad: &[&str], from: char, to: char) -> Vec<String> {
+    let pos = |button: char| {
+        keypad
+            .iter()
+            .enumerate()
+            .find_map(|(row, keys)| keys.find(button).map(|col| (row as isize, col as isize)))
+            .unwrap()
+    };
+    let ((from_row, from_col), (to_row, to_col)) = (pos(from), pos(to));
+    let gap = pos(' ');
+
+    let vertical = if to_row > from_row { "v" } else { "^" }.repeat(from_row.abs_diff(to_row));
+    let horizontal = if to_col > from_col { ">" } else { "<" }.repeat(from_col.abs_diff(to_col));
+
+    let mut moves = vec![];
+    if (from_row, to_col) != gap {
+        moves.push(format!("{}{}A", horizontal, vertical));
+    }
+    if (to_row, from_col) != gap {
+        moves.push(format!("{}{}A", vertical, horizontal));
+    }
+    moves
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reference() {
+        let mut memo = HashMap::new();
+        let lens = ["029A", "980A", "179A", "456A", "379A"].map(|code| presses(code, &NUMERIC, 3, &mut memo));
+        assert_eq!([68, 60, 68, 64, 64], lens);
+    }
+}
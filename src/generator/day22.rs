@@ -0,0 +1,46 @@
+use super::{Generated, Rng};
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// Initial secret numbers of `buyers` buyers.
+///
+/// The answers follow every buyer's 2000 secret numbers and total the first price after each
+/// sequence of four price changes over all buyers.
+pub fn generate(rng: &mut Rng, buyers: usize) -> Generated {
+    let mut input = String::with_capacity(buyers * 9);
+    let mut secret_sum = 0;
+    let mut bananas = HashMap::new();
+
+    for _ in 0..buyers {
+        let mut secret = rng.between(1, 16_777_215) as u64;
+        writeln!(input, "{}", secret).unwrap();
+
+        let mut prices = vec![(secret % 10) as i64];
+        for _ in 0..2000 {
+            secret = next_secret(secret);
+            prices.push((secret % 10) as i64);
+        }
+        secret_sum += secret;
+
+        let mut sold = HashMap::new();
+        for window in prices.windows(5) {
+            let changes = [1, 2, 3, 4].map(|i| window[i] - window[i - 1]);
+            sold.entry(changes).or_insert(window[4]);
+        }
+        for (changes, price) in sold {
+            *bananas.entry(changes).or_insert(0) += price;
+        }
+    }
+
+    Generated {
+        input,
+        a: Some(secret_sum.to_string()),
+        b: Some(bananas.values().max().unwrap_or(&0).to_string()),
+    }
+}
+
+fn next_secret(mut secret: u64) -> u64 {
+    secret = (secret ^ (secret * 64)) % 16_777_216;
+    secret = (secret ^ (secret / 32)) % 16_777_216;
+    (secret ^ (secret * 2048)) % 16_777_216
+}
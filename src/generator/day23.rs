@@ -0,0 +1,87 @@
+use super::{Generated, Rng};
+use itertools::Itertools;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+const CLIQUE_SIZE: usize = 13;
+
+/// A network of `computers` computers with a planted clique of 13 that includes a computer whose
+/// name starts with t. Every other computer has at most 11 connections, so it cannot be part of a
+/// clique of 13 and the planted one is the unique largest.
+pub fn generate(rng: &mut Rng, computers: usize) -> Generated {
+    let computers = computers.clamp(CLIQUE_SIZE + 2, 26 * 26);
+    let mut names = (b'a'..=b'z')
+        .cartesian_product(b'a'..=b'z')
+        .map(|(a, b)| format!("{}{}", a as char, b as char))
+        .collect_vec();
+    rng.shuffle(&mut names);
+    names.truncate(computers);
+
+    // As in the real inputs, the largest clique contains a computer whose name starts with t.
+    if !names[..CLIQUE_SIZE].iter().any(|name| name.starts_with('t')) {
+        let t_name = format!("t{}", (b'a' + rng.below(26) as u8) as char);
+        match names.iter().position(|name| *name == t_name) {
+            Some(idx) => names.swap(0, idx),
+            None => names[0] = t_name,
+        }
+    }
+
+    let mut adjacent = vec![BTreeSet::new(); computers];
+
+    for (a, b) in (0..CLIQUE_SIZE).tuple_combinations() {
+        connect(&mut adjacent, a, b);
+    }
+
+    let max_degree = |a: usize| {
+        if a < CLIQUE_SIZE {
+            CLIQUE_SIZE + 1
+        } else {
+            CLIQUE_SIZE - 2
+        }
+    };
+    for _ in 0..computers * (CLIQUE_SIZE - 2) {
+        let (a, b) = (rng.below(computers), rng.below(computers));
+        let within_clique = a < CLIQUE_SIZE && b < CLIQUE_SIZE;
+        if a != b && !within_clique && adjacent[a].len() < max_degree(a) && adjacent[b].len() < max_degree(b) {
+            connect(&mut adjacent, a, b);
+        }
+    }
+
+    let mut edges = adjacent
+        .iter()
+        .enumerate()
+        .flat_map(|(a, bs)| bs.iter().filter(move |&&b| a < b).map(move |&b| (a, b)))
+        .collect_vec();
+    rng.shuffle(&mut edges);
+
+    let mut input = String::new();
+    for &(a, b) in &edges {
+        let (a, b) = if rng.below(2) == 0 { (a, b) } else { (b, a) };
+        writeln!(input, "{}-{}", names[a], names[b]).unwrap();
+    }
+
+    let adjacent = &adjacent;
+    let triangles = edges
+        .iter()
+        .flat_map(|&(a, b)| {
+            adjacent[a]
+                .iter()
+                .filter(move |&&c| c > b && adjacent[b].contains(&c))
+                .map(move |&c| [a, b, c])
+        })
+        .filter(|triangle| triangle.iter().any(|&v| names[v].starts_with('t')))
+        .count();
+
+    let password = names[..CLIQUE_SIZE].iter().sorted().join(",");
+
+    Generated {
+        input,
+        a: Some(triangles.to_string()),
+        b: Some(password),
+    }
+}
+
+fn connect(adjacent: &mut [BTreeSet<usize>], a: usize, b: usize) {
+    adjacent[a].insert(b);
+    adjacent[b].insert(a);
+}
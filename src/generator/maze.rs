@@ -0,0 +1,70 @@
+use super::Rng;
+use crate::util::grid::{Coord, Dir, Grid, Topology};
+use strum::IntoEnumIterator;
+
+/// Carves a perfect maze, i.e. a spanning tree, over `cells.0 * cells.1` cells with a randomized
+/// depth-first search. Cells lie at even coordinates of the returned `(2 * rows - 1, 2 * cols - 1)`
+/// grid, where `true` marks an open tile.
+///
+/// As there is exactly one path between any two open tiles, blocking any tile of it disconnects
+/// its ends, which makes shortest paths and cut points known by construction.
+pub fn perfect_maze(rng: &mut Rng, cells: Coord) -> Grid<bool> {
+    assert!(cells.0 > 0 && cells.1 > 0, "maze without cells");
+
+    let mut grid = Grid::from_elem(Coord(2 * cells.0 - 1, 2 * cells.1 - 1), false);
+    let mut stack = vec![Coord(0, 0)];
+    grid[Coord(0, 0)] = true;
+
+    while let Some(&idx) = stack.last() {
+        let mut dirs = Dir::iter()
+            .filter(|&dir| {
+                let next_idx = idx + dir.to_coord() * 2;
+                grid.get(next_idx).is_some_and(|&open| !open)
+            })
+            .collect::<Vec<_>>();
+
+        if dirs.is_empty() {
+            stack.pop();
+            continue;
+        }
+
+        rng.shuffle(&mut dirs);
+        let dir = dirs[0];
+        grid[idx + dir] = true;
+        grid[idx + dir.to_coord() * 2] = true;
+        stack.push(idx + dir.to_coord() * 2);
+    }
+
+    grid
+}
+
+/// Returns the tiles of the path from `from` to `to` through the open tiles of `maze`, including
+/// both ends.
+pub fn maze_path(maze: &Grid<bool>, from: Coord, to: Coord) -> Vec<Coord> {
+    let field = maze.distance_field([from], |_, next| maze[next].then_some(1));
+    let mut path = field.path_from(maze, to).expect("maze is connected");
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_perfect_maze() {
+        let mut rng = Rng::new(3);
+        let maze = perfect_maze(&mut rng, Coord(6, 9));
+        assert_eq!(Coord(11, 17), maze.dim());
+
+        // A spanning tree over 54 cells has 53 edges, each opening one tile between two cells.
+        assert_eq!(54 + 53, maze.iter().filter(|&&open| open).count());
+
+        let dist = maze.distances([Coord(0, 0)], |idx| maze[idx]);
+        assert!(maze.indexed_iter().all(|(idx, &open)| open == dist[idx].is_some()));
+
+        let path = maze_path(&maze, Coord(0, 0), Coord(10, 16));
+        assert_eq!((Coord(0, 0), Coord(10, 16)), (path[0], path[path.len() - 1]));
+        assert_eq!(Some(path.len() as u32 - 1), dist[Coord(10, 16)]);
+    }
+}
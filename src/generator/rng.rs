@@ -0,0 +1,58 @@
+/// Small seeded SplitMix64 generator, so that generated inputs are reproducible without pulling
+/// in a dependency.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a value in `0..n`. Panics if `n == 0`.
+    pub fn below(&mut self, n: usize) -> usize {
+        assert!(n > 0, "empty range");
+        ((self.next_u64() as u128 * n as u128) >> 64) as usize
+    }
+
+    /// Returns a value in `lo..=hi`.
+    pub fn between(&mut self, lo: i64, hi: i64) -> i64 {
+        lo + self.below((hi - lo + 1) as usize) as i64
+    }
+
+    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        for i in (1..slice.len()).rev() {
+            slice.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng() {
+        let a = (0..10).map(|_| Rng::new(7).next_u64()).collect::<Vec<_>>();
+        assert!(a.iter().all(|&x| x == a[0]));
+
+        let mut rng = Rng::new(1);
+        assert!((0..1000).all(|_| rng.below(3) < 3));
+        assert!((0..1000).all(|_| (-2..=2).contains(&rng.between(-2, 2))));
+
+        let mut values = (0..20).collect::<Vec<_>>();
+        rng.shuffle(&mut values);
+        assert_ne!((0..20).collect::<Vec<_>>(), values);
+        values.sort();
+        assert_eq!((0..20).collect::<Vec<_>>(), values);
+    }
+}
@@ -1,37 +1,99 @@
+use crate::util::stream::StreamError;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
 use std::time::Instant;
 
+pub mod generator;
 pub mod util;
 
 #[macro_export]
 macro_rules! aoc_day {
     ($t:ty, $expected_a:expr, $expected_b:expr) => {
         fn main() {
+            if let Some(path) = std::env::args().nth(1) {
+                $crate::run_aoc_day_file::<$t>(&path);
+                return;
+            }
+
             let test_input = include_str!("test.txt");
             let main_input = include_str!("main.txt");
             $crate::run_aoc_day::<$t>(test_input, main_input, $expected_a, Some($expected_b));
         }
+
+        #[cfg(test)]
+        #[test]
+        fn test_example() {
+            $crate::test_aoc_day::<$t>(include_str!("test.txt"), $expected_a, Some($expected_b));
+        }
     };
     ($t:ty, $expected_a:expr) => {
         fn main() {
+            if let Some(path) = std::env::args().nth(1) {
+                $crate::run_aoc_day_file::<$t>(&path);
+                return;
+            }
+
             let test_input = include_str!("test.txt");
             let main_input = include_str!("main.txt");
             $crate::run_aoc_day::<$t>(test_input, main_input, $expected_a, None);
         }
+
+        #[cfg(test)]
+        #[test]
+        fn test_example() {
+            $crate::test_aoc_day::<$t>(include_str!("test.txt"), $expected_a, None);
+        }
     };
 }
 
 pub trait AocDay {
     fn from(input: &str) -> Self;
+
+    /// Parses the input from a reader. Reads the whole input into a string by default; days that
+    /// can parse line by line override this to run on inputs too large to hold as one string.
+    fn from_reader<R: BufRead>(mut reader: R) -> Result<Self, StreamError>
+    where
+        Self: Sized,
+    {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        Ok(Self::from(&input))
+    }
+
     fn a(&self) -> String;
     fn b(&self) -> String;
 }
 
-pub fn run_aoc_day<T: AocDay>(
-    test_input: &str,
-    main_input: &str,
-    expected_a: &str,
-    expected_b: Option<&str>,
-) {
+/// Runs both parts on the input file at `path`, which is passed to [`AocDay::from_reader`].
+pub fn run_aoc_day_file<T: AocDay>(path: &str) {
+    let before_parse = Instant::now();
+    let file = File::open(path).unwrap_or_else(|e| panic!("cannot open {}: {}", path, e));
+    let input = T::from_reader(BufReader::new(file)).unwrap_or_else(|e| panic!("{}: {}", path, e));
+    println!("Parsed in {:?}", before_parse.elapsed());
+
+    let before_a = Instant::now();
+    let a = input.a();
+    println!("A: {} in {:?}", a, before_a.elapsed());
+
+    let before_b = Instant::now();
+    let b = input.b();
+    println!("B: {} in {:?}", b, before_b.elapsed());
+}
+
+/// Asserts that the example input gives the expected answers, like the checks of [`run_aoc_day`].
+pub fn test_aoc_day<T: AocDay>(test_input: &str, expected_a: &str, expected_b: Option<&str>) {
+    let (test_a, test_b) = match test_input.split_once("=====\n") {
+        Some((a, b)) => (T::from(a), T::from(b)),
+        None => (T::from(test_input), T::from(test_input)),
+    };
+
+    assert_eq!(expected_a, test_a.a());
+    if let Some(expected_b) = expected_b {
+        assert_eq!(expected_b, test_b.b());
+    }
+}
+
+pub fn run_aoc_day<T: AocDay>(test_input: &str, main_input: &str, expected_a: &str, expected_b: Option<&str>) {
     let (test_a, test_b) = match test_input.split_once("=====\n") {
         Some((a, b)) => (T::from(&a), T::from(&b)),
         None => (T::from(&test_input), T::from(&test_input)),
@@ -85,8 +147,13 @@ macro_rules! init {
         use std::path::Path;
 
         fn main() {
-            let input = include_str!("main.txt");
-            let (a, b) = try_run(input);
+            let input = match std::env::args().nth(1) {
+                Some(path) => {
+                    read_to_string(Path::new(&path)).unwrap_or_else(|e| panic!("cannot open {}: {}", path, e))
+                }
+                None => include_str!("main.txt").to_string(),
+            };
+            let (a, b) = try_run(&input);
             print!("A: {}\nB: {}\n", a, b);
         }
 
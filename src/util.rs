@@ -1,3 +1,8 @@
-pub mod parse_matrix;
-pub mod parse;
+pub mod captures;
 pub mod grid;
+pub mod ints;
+pub mod parse;
+pub mod parse_matrix;
+pub mod stream;
+
+pub use ints::{ints, ints_n};
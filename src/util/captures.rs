@@ -0,0 +1,216 @@
+use crate::util::parse::ParseError;
+use regex::{Captures, Match, Regex};
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[doc(hidden)]
+pub use regex;
+
+/// A record that is extracted from the capture groups of a regex match.
+///
+/// Usually implemented with [`from_captures!`](crate::from_captures).
+pub trait FromCaptures: Sized {
+    fn regex() -> &'static Regex;
+
+    fn from_captures(caps: &Captures) -> Result<Self, ParseError>;
+
+    /// Extracts a record from the first match in `s`.
+    fn extract(s: &str) -> Result<Self, ParseError> {
+        let caps = Self::regex()
+            .captures(s)
+            .ok_or_else(|| ParseError::new("regex", s, format!("no match for {}", Self::regex())))?;
+        Self::from_captures(&caps)
+    }
+
+    /// Extracts a record from every non-overlapping match in `s`.
+    fn extract_all(s: &str) -> Result<Vec<Self>, ParseError> {
+        Self::regex()
+            .captures_iter(s)
+            .map(|caps| Self::from_captures(&caps))
+            .collect()
+    }
+}
+
+/// Names a capture group by index or by name.
+pub trait GroupKey: Copy + Display {
+    fn get<'h>(self, caps: &Captures<'h>) -> Option<Match<'h>>;
+}
+
+impl GroupKey for usize {
+    fn get<'h>(self, caps: &Captures<'h>) -> Option<Match<'h>> {
+        caps.get(self)
+    }
+}
+
+impl GroupKey for &str {
+    fn get<'h>(self, caps: &Captures<'h>) -> Option<Match<'h>> {
+        caps.name(self)
+    }
+}
+
+/// Parses capture group `key` with `parser`, reporting errors against `field` at the group's offset
+/// in the haystack.
+pub fn parse_group<K, T, F>(caps: &Captures, key: K, field: &'static str, parser: F) -> Result<T, ParseError>
+where
+    K: GroupKey,
+    F: FnOnce(&str) -> Result<T, ParseError>,
+{
+    let m = key.get(caps).ok_or_else(|| {
+        let whole = caps.get(0).unwrap();
+        let mut e = ParseError::new(field, whole.as_str(), format!("group {} did not match", key));
+        e.offset = whole.start();
+        e
+    })?;
+
+    parser(m.as_str()).map_err(|e| ParseError {
+        offset: e.offset + m.start(),
+        ..e
+    })
+}
+
+/// Parses `s` with its [`FromStr`] implementation.
+pub fn parse_str<T>(field: &'static str) -> impl FnOnce(&str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    move |s| s.parse::<T>().map_err(|e| ParseError::new(field, s, e.to_string()))
+}
+
+/// Declares a struct together with the regex its fields are extracted from, and implements
+/// [`FromCaptures`](crate::util::captures::FromCaptures) for it.
+///
+/// Every field names its capture group by index or name. A field can also be built with `From`
+/// from a tuple of groups, or parsed by a custom parser such as a
+/// [`try_parse!`](crate::try_parse) closure:
+///
+/// ```ignore
+/// from_captures! {
+///     #[regex = r"(?<name>\w+) at (\d+),(\d+): ([\d,]+)"]
+///     struct Record {
+///         name: String = "name",
+///         pos: Coord = (3, 2),
+///         values: Vec<u8> = 4 => try_parse!([u8 ","]),
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! from_captures {
+    (@value $caps: ident, $field: ident, ($($group: literal),+)) => {
+        From::from(($($crate::from_captures!(@value $caps, $field, $group),)+))
+    };
+    (@value $caps: ident, $field: ident, $group: literal) => {
+        $crate::util::captures::parse_group(
+            $caps,
+            $group,
+            stringify!($field),
+            $crate::util::captures::parse_str(stringify!($field)),
+        )?
+    };
+    (@value $caps: ident, $field: ident, $group: literal => $parser: expr) => {
+        $crate::util::captures::parse_group($caps, $group, stringify!($field), $parser)?
+    };
+    (
+        #[regex = $regex: literal]
+        $(#[$attr: meta])*
+        $vis: vis struct $name: ident {
+            $($field_vis: vis $field: ident: $ty: ty = $group: tt $(=> $parser: expr)?),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis struct $name {
+            $($field_vis $field: $ty),*
+        }
+
+        impl $crate::util::captures::FromCaptures for $name {
+            fn regex() -> &'static $crate::util::captures::regex::Regex {
+                static REGEX: std::sync::LazyLock<$crate::util::captures::regex::Regex> =
+                    std::sync::LazyLock::new(|| $crate::util::captures::regex::Regex::new($regex).unwrap());
+                &REGEX
+            }
+
+            fn from_captures(
+                caps: &$crate::util::captures::regex::Captures,
+            ) -> Result<Self, $crate::util::parse::ParseError> {
+                Ok($name {
+                    $($field: $crate::from_captures!(@value caps, $field, $group $(=> $parser)?)),*
+                })
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::try_parse;
+    use crate::util::grid::Coord;
+
+    from_captures! {
+        #[regex = r"(?<name>\w+) at (\d+),(\d+): ([\d,]+)"]
+        #[derive(Debug, PartialEq)]
+        struct Record {
+            name: String = "name",
+            pos: Coord = (3, 2),
+            values: Vec<u8> = 4 => try_parse!([u8 ","]),
+        }
+    }
+
+    from_captures! {
+        #[regex = r"x=(-?\d+)(?:, y=(-?\d+))?"]
+        #[derive(Debug, PartialEq)]
+        struct Point {
+            x: i32 = 1,
+            y: i32 = 2,
+        }
+    }
+
+    mod shadowed {
+        // A local `regex` module must not capture the paths in the expansion.
+        mod regex {}
+
+        from_captures! {
+            #[regex = r"(\d+)"]
+            pub struct Number {
+                pub n: u32 = 1,
+            }
+        }
+    }
+
+    #[test]
+    fn test_extract() {
+        assert_eq!(
+            Record {
+                name: "a".to_string(),
+                pos: Coord(3, 2),
+                values: vec![1, 2]
+            },
+            Record::extract("a at 2,3: 1,2").unwrap()
+        );
+        assert_eq!(
+            vec![Point { x: 1, y: -2 }, Point { x: 3, y: 4 }],
+            Point::extract_all("x=1, y=-2\nx=3, y=4\n").unwrap()
+        );
+        assert!(Point::extract("nothing").is_err());
+    }
+
+    #[test]
+    fn test_extract_shadowed_regex() {
+        assert_eq!(7, shadowed::Number::extract("x7").unwrap().n);
+    }
+
+    #[test]
+    fn test_extract_errors() {
+        let e = Record::extract("a at 2,3: 1,300").unwrap_err();
+        assert_eq!(("u8", "300", 12), (e.pattern, e.input.as_str(), e.offset));
+
+        let e = Point::extract_all("x=1, y=2\nx=99999999999").unwrap_err();
+        assert_eq!(("x", 11), (e.pattern, e.offset));
+
+        let e = Point::extract("x=1").unwrap_err();
+        assert_eq!(
+            "cannot parse \"x=1\" at byte 0 as `y`: group 2 did not match",
+            e.to_string()
+        );
+    }
+}
@@ -1,7 +1,33 @@
+mod array;
+mod bit_grid;
 mod coord;
+mod diff;
 mod dir;
+mod distance;
 mod grid;
+mod image;
+mod pattern;
+mod recorder;
+mod rect;
+mod region;
+mod sparse;
+mod tile;
+mod topology;
+mod torus;
+mod walker;
 
+pub use bit_grid::BitGrid;
 pub use coord::Coord;
-pub use dir::Dir;
-pub use grid::Grid;
+pub use diff::{CellChange, GridDiff};
+pub use dir::{Dir, DirSet, ParseDirError};
+pub use distance::DistanceField;
+pub use grid::{Connectivity, Grid};
+pub use pattern::{Orientation, Pattern, PatternMatch};
+pub use recorder::{Colour, FrameRecorder};
+pub use rect::{Rect, RectIter};
+pub use region::{RegionMetrics, Regions};
+pub use sparse::SparseGrid;
+pub use tile::{CharTile, TileError, UnknownTile};
+pub use topology::{Bfs, Topology};
+pub use torus::TorusGrid;
+pub use walker::{Walk, WalkOutcome};
@@ -0,0 +1,72 @@
+use super::{Coord, Grid};
+use ndarray::{Array2, ArrayView2, ArrayViewMut2};
+
+impl<T> Grid<T> {
+    /// Borrows the grid as an ndarray view, indexed by `[row, col]`.
+    pub fn view(&self) -> ArrayView2<'_, T> {
+        let dim = self.dim();
+        ArrayView2::from_shape((dim.0 as usize, dim.1 as usize), self.as_slice()).unwrap()
+    }
+
+    pub fn view_mut(&mut self) -> ArrayViewMut2<'_, T> {
+        let dim = self.dim();
+        ArrayViewMut2::from_shape((dim.0 as usize, dim.1 as usize), self.as_mut_slice()).unwrap()
+    }
+}
+
+impl<T> From<Array2<T>> for Grid<T> {
+    fn from(array: Array2<T>) -> Self {
+        let (rows, cols) = array.dim();
+        Grid::from_vec(Coord(rows as isize, cols as isize), array.into_iter().collect())
+    }
+}
+
+impl<T> From<Grid<T>> for Array2<T> {
+    fn from(grid: Grid<T>) -> Self {
+        let dim = grid.dim();
+        Array2::from_shape_vec((dim.0 as usize, dim.1 as usize), grid.into_vec()).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::{array, s};
+
+    #[test]
+    fn test_from_array() {
+        let grid = Grid::from(array![[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(Coord(2, 3), grid.dim());
+        assert_eq!(6, grid[Coord(1, 2)]);
+
+        let grid = Grid::from(array![[1, 2], [3, 4]].reversed_axes());
+        assert_eq!(vec![1, 3, 2, 4], grid.into_vec());
+    }
+
+    #[test]
+    fn test_into_array() {
+        let grid = Grid::from_vec(Coord(2, 3), vec![1, 2, 3, 4, 5, 6]);
+        let array = Array2::from(grid.clone());
+
+        assert_eq!(array![[1, 2, 3], [4, 5, 6]], array);
+        assert_eq!(grid, Grid::from(array));
+    }
+
+    #[test]
+    fn test_view() {
+        let mut grid = Grid::from_vec(Coord(2, 3), vec![1, 2, 3, 4, 5, 6]);
+
+        assert_eq!(array![[2, 3], [5, 6]], grid.view().slice(s![.., 1..]));
+        assert_eq!(
+            vec![12, 16],
+            grid.view()
+                .windows((2, 2))
+                .into_iter()
+                .map(|w| w.sum())
+                .collect::<Vec<_>>()
+        );
+
+        grid.view_mut().column_mut(0).fill(0);
+        assert_eq!(vec![0, 2, 3, 0, 5, 6], grid.into_vec());
+    }
+}
@@ -0,0 +1,295 @@
+use super::{Coord, Grid, Rect, Topology};
+use std::fmt::{Display, Formatter};
+use std::ops::{BitAnd, BitOr, Index};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A boolean grid storing one bit per cell.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct BitGrid {
+    dim: Coord,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    pub fn new(dim: Coord) -> Self {
+        BitGrid::from_elem(dim, false)
+    }
+
+    pub fn from_elem(dim: Coord, elem: bool) -> Self {
+        if dim.0 < 0 || dim.1 < 0 {
+            panic!("dim < 0");
+        }
+
+        let len = dim.0.checked_mul(dim.1).unwrap() as usize;
+        let mut bit_grid = BitGrid {
+            dim,
+            words: vec![0; len.div_ceil(WORD_BITS)],
+        };
+        if elem {
+            bit_grid.fill(true);
+        }
+
+        bit_grid
+    }
+
+    pub fn dim(&self) -> Coord {
+        self.dim
+    }
+
+    pub fn len(&self) -> usize {
+        (self.dim.0 * self.dim.1) as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn get(&self, idx: Coord) -> Option<bool> {
+        let pos = self.pos(idx)?;
+        Some(self.words[pos / WORD_BITS] & (1 << (pos % WORD_BITS)) != 0)
+    }
+
+    /// Returns `false` for coordinates outside of the grid.
+    pub fn contains(&self, idx: Coord) -> bool {
+        self.get(idx) == Some(true)
+    }
+
+    pub fn set(&mut self, idx: Coord, elem: bool) {
+        let pos = self.pos(idx).unwrap();
+        let mask = 1 << (pos % WORD_BITS);
+
+        if elem {
+            self.words[pos / WORD_BITS] |= mask;
+        } else {
+            self.words[pos / WORD_BITS] &= !mask;
+        }
+    }
+
+    /// Sets the cell and returns whether it was previously unset.
+    pub fn insert(&mut self, idx: Coord) -> bool {
+        let was_set = self[idx];
+        self.set(idx, true);
+        !was_set
+    }
+
+    /// Unsets the cell and returns whether it was previously set.
+    pub fn remove(&mut self, idx: Coord) -> bool {
+        let was_set = self[idx];
+        self.set(idx, false);
+        was_set
+    }
+
+    pub fn fill(&mut self, elem: bool) {
+        self.words.fill(if elem { u64::MAX } else { 0 });
+        self.clear_tail();
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    pub fn union_with(&mut self, other: &BitGrid) {
+        self.zip_words_with(other, |a, b| a | b);
+    }
+
+    pub fn intersect_with(&mut self, other: &BitGrid) {
+        self.zip_words_with(other, |a, b| a & b);
+    }
+
+    pub fn difference_with(&mut self, other: &BitGrid) {
+        self.zip_words_with(other, |a, b| a & !b);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len()).map(|pos| self.words[pos / WORD_BITS] & (1 << (pos % WORD_BITS)) != 0)
+    }
+
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Coord, bool)> + '_ {
+        Rect::from_dim(self.dim).iter().zip(self.iter())
+    }
+
+    /// Iterates over the set cells in row-major order.
+    pub fn iter_ones(&self) -> impl Iterator<Item = Coord> + '_ {
+        self.words.iter().enumerate().flat_map(move |(word_idx, &word)| {
+            let mut word = word;
+            std::iter::from_fn(move || {
+                if word == 0 {
+                    return None;
+                }
+
+                let pos = word_idx * WORD_BITS + word.trailing_zeros() as usize;
+                word &= word - 1;
+                let pos = pos as isize;
+                Some(Coord(pos / self.dim.1, pos % self.dim.1))
+            })
+        })
+    }
+
+    fn pos(&self, idx: Coord) -> Option<usize> {
+        if idx.0 >= 0 && idx.1 >= 0 && idx.0 < self.dim.0 && idx.1 < self.dim.1 {
+            Some((idx.0 * self.dim.1 + idx.1) as usize)
+        } else {
+            None
+        }
+    }
+
+    fn zip_words_with<F>(&mut self, other: &BitGrid, f: F)
+    where
+        F: Fn(u64, u64) -> u64,
+    {
+        if self.dim != other.dim {
+            panic!("self.dim != other.dim");
+        }
+
+        for (a, &b) in self.words.iter_mut().zip(&other.words) {
+            *a = f(*a, b);
+        }
+    }
+
+    fn clear_tail(&mut self) {
+        let tail_bits = self.len() % WORD_BITS;
+        if tail_bits != 0 {
+            if let Some(last) = self.words.last_mut() {
+                *last &= (1 << tail_bits) - 1;
+            }
+        }
+    }
+}
+
+impl Index<Coord> for BitGrid {
+    type Output = bool;
+
+    fn index(&self, idx: Coord) -> &Self::Output {
+        if self.get(idx).unwrap() {
+            &true
+        } else {
+            &false
+        }
+    }
+}
+
+impl Topology for BitGrid {
+    fn dim(&self) -> Coord {
+        self.dim
+    }
+
+    fn resolve(&self, idx: Coord) -> Option<Coord> {
+        self.pos(idx).map(|_| idx)
+    }
+}
+
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> Self {
+        let mut bit_grid = BitGrid::new(grid.dim());
+        for (idx, &elem) in grid.indexed_iter() {
+            if elem {
+                bit_grid.set(idx, true);
+            }
+        }
+        bit_grid
+    }
+}
+
+impl From<&BitGrid> for Grid<bool> {
+    fn from(bit_grid: &BitGrid) -> Self {
+        Grid::from_vec(bit_grid.dim, bit_grid.iter().collect())
+    }
+}
+
+impl BitOr for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        let mut bit_grid = self.clone();
+        bit_grid.union_with(rhs);
+        bit_grid
+    }
+}
+
+impl BitAnd for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        let mut bit_grid = self.clone();
+        bit_grid.intersect_with(rhs);
+        bit_grid
+    }
+}
+
+impl Display for BitGrid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in 0..self.dim.0 {
+            for col in 0..self.dim.1 {
+                if self[Coord(row, col)] {
+                    write!(f, "#")?
+                } else {
+                    write!(f, ".")?
+                }
+            }
+            f.write_str("\n")?
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_and_set() {
+        let mut bit_grid = BitGrid::new(Coord(9, 9));
+
+        assert!(bit_grid.insert(Coord(8, 8)));
+        assert!(!bit_grid.insert(Coord(8, 8)));
+        bit_grid.set(Coord(0, 1), true);
+
+        assert_eq!(Some(true), bit_grid.get(Coord(0, 1)));
+        assert_eq!(Some(false), bit_grid.get(Coord(1, 0)));
+        assert_eq!(None, bit_grid.get(Coord(9, 0)));
+        assert!(!bit_grid.contains(Coord(-1, 0)));
+        assert!(bit_grid[Coord(8, 8)]);
+        assert_eq!(2, bit_grid.count_ones());
+
+        assert!(bit_grid.remove(Coord(8, 8)));
+        assert!(!bit_grid[Coord(8, 8)]);
+        assert_eq!(1, bit_grid.count_ones());
+    }
+
+    #[test]
+    fn test_fill_and_iter_ones() {
+        let mut bit_grid = BitGrid::from_elem(Coord(3, 45), true);
+        assert_eq!(135, bit_grid.count_ones());
+        assert_eq!(135, bit_grid.iter_ones().count());
+
+        bit_grid.fill(false);
+        bit_grid.set(Coord(2, 44), true);
+        bit_grid.set(Coord(1, 20), true);
+        assert_eq!(
+            vec![Coord(1, 20), Coord(2, 44)],
+            bit_grid.iter_ones().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = BitGrid::from(&Grid::from_vec(
+            Coord(2, 3),
+            vec![true, true, false, true, false, false],
+        ));
+        let b = BitGrid::from(&Grid::from_vec(Coord(2, 3), vec![false, true, true, true, false, true]));
+
+        assert_eq!("###\n#.#\n", (&a | &b).to_string());
+        assert_eq!(".#.\n#..\n", (&a & &b).to_string());
+
+        let mut c = a.clone();
+        c.difference_with(&b);
+        assert_eq!("#..\n...\n", Grid::<bool>::from(&c).to_string());
+        assert_eq!(
+            vec![(Coord(0, 0), true), (Coord(0, 1), false)],
+            c.indexed_iter().take(2).collect::<Vec<_>>()
+        );
+    }
+}
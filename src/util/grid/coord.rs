@@ -1,9 +1,48 @@
 use super::Dir;
-use std::ops::{Add, Mul};
+use num::integer::gcd;
+use std::num::TryFromIntError;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
 
-#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+/// A `(row, col)` coordinate. The derived ordering is row-major.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
 pub struct Coord(pub isize, pub isize);
 
+impl Coord {
+    pub fn manhattan(self, rhs: Coord) -> usize {
+        self.0.abs_diff(rhs.0) + self.1.abs_diff(rhs.1)
+    }
+
+    pub fn chebyshev(self, rhs: Coord) -> usize {
+        self.0.abs_diff(rhs.0).max(self.1.abs_diff(rhs.1))
+    }
+
+    pub fn signum(self) -> Coord {
+        Coord(self.0.signum(), self.1.signum())
+    }
+
+    /// Divides both components by their greatest common divisor, yielding the shortest step
+    /// with the same direction. `Coord(0, 0)` is returned unchanged.
+    pub fn reduce(self) -> Coord {
+        match gcd(self.0, self.1) {
+            0 => self,
+            divisor => Coord(self.0 / divisor, self.1 / divisor),
+        }
+    }
+
+    pub fn rem_euclid(self, rhs: Coord) -> Coord {
+        Coord(self.0.rem_euclid(rhs.0), self.1.rem_euclid(rhs.1))
+    }
+
+    /// Iterates over all coordinates within Manhattan distance `radius` in row-major order.
+    pub fn manhattan_ball(self, radius: usize) -> impl Iterator<Item = Coord> {
+        let radius = radius as isize;
+        (-radius..=radius).flat_map(move |d_row| {
+            let d_col_max = radius - d_row.abs();
+            (-d_col_max..=d_col_max).map(move |d_col| self + Coord(d_row, d_col))
+        })
+    }
+}
+
 impl Add<Coord> for Coord {
     type Output = Coord;
 
@@ -12,6 +51,34 @@ impl Add<Coord> for Coord {
     }
 }
 
+impl AddAssign<Coord> for Coord {
+    fn add_assign(&mut self, rhs: Coord) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Coord> for Coord {
+    type Output = Coord;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Coord(self.0 - rhs.0, self.1 - rhs.1)
+    }
+}
+
+impl SubAssign<Coord> for Coord {
+    fn sub_assign(&mut self, rhs: Coord) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Coord {
+    type Output = Coord;
+
+    fn neg(self) -> Self::Output {
+        Coord(-self.0, -self.1)
+    }
+}
+
 impl Mul<Coord> for isize {
     type Output = Coord;
 
@@ -20,6 +87,20 @@ impl Mul<Coord> for isize {
     }
 }
 
+impl Mul<isize> for Coord {
+    type Output = Coord;
+
+    fn mul(self, rhs: isize) -> Self::Output {
+        rhs * self
+    }
+}
+
+impl MulAssign<isize> for Coord {
+    fn mul_assign(&mut self, rhs: isize) {
+        *self = rhs * *self;
+    }
+}
+
 impl Add<Dir> for Coord {
     type Output = Coord;
 
@@ -28,6 +109,59 @@ impl Add<Dir> for Coord {
     }
 }
 
+impl AddAssign<Dir> for Coord {
+    fn add_assign(&mut self, rhs: Dir) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Dir> for Coord {
+    type Output = Coord;
+
+    fn sub(self, rhs: Dir) -> Self::Output {
+        self - rhs.to_coord()
+    }
+}
+
+impl From<(isize, isize)> for Coord {
+    fn from(value: (isize, isize)) -> Self {
+        Coord(value.0, value.1)
+    }
+}
+
+impl TryFrom<(usize, usize)> for Coord {
+    type Error = TryFromIntError;
+
+    fn try_from(value: (usize, usize)) -> Result<Self, Self::Error> {
+        Ok(Coord(value.0.try_into()?, value.1.try_into()?))
+    }
+}
+
+impl TryFrom<[usize; 2]> for Coord {
+    type Error = TryFromIntError;
+
+    fn try_from(value: [usize; 2]) -> Result<Self, Self::Error> {
+        Coord::try_from((value[0], value[1]))
+    }
+}
+
+impl TryFrom<Coord> for (usize, usize) {
+    type Error = TryFromIntError;
+
+    fn try_from(value: Coord) -> Result<Self, Self::Error> {
+        Ok((value.0.try_into()?, value.1.try_into()?))
+    }
+}
+
+impl TryFrom<Coord> for [usize; 2] {
+    type Error = TryFromIntError;
+
+    fn try_from(value: Coord) -> Result<Self, Self::Error> {
+        let (row, col) = value.try_into()?;
+        Ok([row, col])
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -37,5 +171,57 @@ mod tests {
         assert_eq!(Coord(4, 6), Coord(1, 2) + Coord(3, 4));
         assert_eq!(Coord(3, 6), 3 * Coord(1, 2));
         assert_eq!(Coord(3, 5), Coord(4, 5) + Dir::N);
+        assert_eq!(Coord(-2, 1), Coord(1, 2) - Coord(3, 1));
+        assert_eq!(Coord(-1, 2), -Coord(1, -2));
+        assert_eq!(Coord(3, 6), Coord(1, 2) * 3);
+        assert_eq!(Coord(5, 5), Coord(4, 5) - Dir::N);
+
+        let mut coord = Coord(1, 2);
+        coord += Coord(3, 4);
+        coord -= Coord(1, 1);
+        coord *= 2;
+        coord += Dir::W;
+        assert_eq!(Coord(6, 9), coord);
+    }
+
+    #[test]
+    fn test_metrics() {
+        assert_eq!(7, Coord(1, -2).manhattan(Coord(-1, 3)));
+        assert_eq!(5, Coord(1, -2).chebyshev(Coord(-1, 3)));
+        assert_eq!(Coord(-1, 0), Coord(-7, 0).signum());
+        assert_eq!(Coord(-2, 3), Coord(-4, 6).reduce());
+        assert_eq!(Coord(0, 1), Coord(0, 5).reduce());
+        assert_eq!(Coord(0, 0), Coord(0, 0).reduce());
+        assert_eq!(Coord(5, 1), Coord(-1, 8).rem_euclid(Coord(6, 7)));
+    }
+
+    #[test]
+    fn test_manhattan_ball() {
+        let ball = Coord(2, 3).manhattan_ball(1).collect::<Vec<_>>();
+        assert_eq!(
+            vec![Coord(1, 3), Coord(2, 2), Coord(2, 3), Coord(2, 4), Coord(3, 3)],
+            ball
+        );
+
+        assert_eq!(vec![Coord(0, 0)], Coord(0, 0).manhattan_ball(0).collect::<Vec<_>>());
+        assert_eq!(41, Coord(0, 0).manhattan_ball(4).count());
+        assert!(Coord(0, 0).manhattan_ball(4).all(|idx| idx.manhattan(Coord(0, 0)) <= 4));
+    }
+
+    #[test]
+    fn test_ord() {
+        assert!(Coord(0, 5) < Coord(1, 0));
+        assert!(Coord(1, 0) < Coord(1, 1));
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(Ok(Coord(1, 2)), Coord::try_from((1usize, 2usize)));
+        assert_eq!(Ok(Coord(1, 2)), Coord::try_from([1usize, 2usize]));
+        assert!(Coord::try_from([usize::MAX, 0]).is_err());
+
+        assert_eq!(Ok((1usize, 2usize)), Coord(1, 2).try_into());
+        assert_eq!(Ok([1usize, 2usize]), Coord(1, 2).try_into());
+        assert!(<[usize; 2]>::try_from(Coord(-1, 2)).is_err());
     }
 }
@@ -0,0 +1,142 @@
+use super::{Colour, Coord, Grid};
+
+/// A cell whose value differs between two grids.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct CellChange<'a, T> {
+    pub idx: Coord,
+    pub old: &'a T,
+    pub new: &'a T,
+}
+
+/// The changed cells between an old and a new grid of the same dimension.
+#[derive(Clone, Debug)]
+pub struct GridDiff<'a, T> {
+    old: &'a Grid<T>,
+    new: &'a Grid<T>,
+    changes: Vec<CellChange<'a, T>>,
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Compares `self` as the old state with `other` as the new state.
+    pub fn diff<'a>(&'a self, other: &'a Grid<T>) -> GridDiff<'a, T> {
+        if self.dim() != other.dim() {
+            panic!("self.dim != other.dim");
+        }
+
+        let changes = self
+            .indexed_iter()
+            .zip(other.iter())
+            .filter(|((_, old), new)| old != new)
+            .map(|((idx, old), new)| CellChange { idx, old, new })
+            .collect();
+
+        GridDiff {
+            old: self,
+            new: other,
+            changes,
+        }
+    }
+}
+
+impl<'a, T> GridDiff<'a, T> {
+    pub fn changes(&self) -> &[CellChange<'a, T>] {
+        &self.changes
+    }
+
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Renders the old and new grid next to each other, marking rows that changed with `*`.
+    pub fn render_side_by_side<F>(&self, f: F) -> String
+    where
+        F: Fn(&T) -> char,
+    {
+        let dim = self.old.dim();
+        let mut s = String::new();
+
+        for row in 0..dim.0 {
+            s.extend((0..dim.1).map(|col| f(&self.old[Coord(row, col)])));
+            s += " | ";
+            s.extend((0..dim.1).map(|col| f(&self.new[Coord(row, col)])));
+            if self.changes.iter().any(|change| change.idx.0 == row) {
+                s += " *";
+            }
+            s.push('\n');
+        }
+
+        s
+    }
+
+    /// Renders the new grid with the changed cells in `colour`.
+    pub fn render_highlighted<F>(&self, colour: Colour, f: F) -> String
+    where
+        F: Fn(&T) -> char,
+    {
+        let dim = self.new.dim();
+        let mut s = String::new();
+        let mut changes = self.changes.iter().peekable();
+
+        for row in 0..dim.0 {
+            for col in 0..dim.1 {
+                let idx = Coord(row, col);
+                let c = f(&self.new[idx]);
+
+                if changes.next_if(|change| change.idx == idx).is_some() {
+                    s += &format!("\x1b[{}m{}\x1b[0m", colour as u8, c);
+                } else {
+                    s.push(c);
+                }
+            }
+            s.push('\n');
+        }
+
+        s
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff() {
+        let old: Grid<char> = Grid::from_str("#.O.\n....\n");
+        let new: Grid<char> = Grid::from_str("#..O\n....\n");
+
+        let diff = old.diff(&new);
+        assert_eq!(
+            vec![
+                CellChange {
+                    idx: Coord(0, 2),
+                    old: &'O',
+                    new: &'.'
+                },
+                CellChange {
+                    idx: Coord(0, 3),
+                    old: &'.',
+                    new: &'O'
+                },
+            ],
+            diff.changes()
+        );
+        assert!(old.diff(&old).is_empty());
+    }
+
+    #[test]
+    fn test_render() {
+        let old: Grid<char> = Grid::from_str("@.\n..\n");
+        let new: Grid<char> = Grid::from_str("..\n@.\n");
+        let diff = old.diff(&new);
+
+        assert_eq!("@. | .. *\n.. | @. *\n", diff.render_side_by_side(|&c| c));
+        assert_eq!(
+            "\x1b[32m.\x1b[0m.\n\x1b[32m@\x1b[0m.\n",
+            diff.render_highlighted(Colour::Green, |&c| c)
+        );
+    }
+}
@@ -1,7 +1,11 @@
 use super::Coord;
-use strum::{EnumIter, FromRepr};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::ops::{BitOr, BitOrAssign};
+use std::str::FromStr;
+use strum::{EnumIter, FromRepr, IntoEnumIterator};
 
-#[derive(Copy, Clone, PartialEq, Eq, Debug, EnumIter, FromRepr)]
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug, EnumIter, FromRepr)]
 pub enum Dir {
     N = 0,
     E,
@@ -10,7 +14,7 @@ pub enum Dir {
 }
 
 impl Dir {
-    pub fn to_coord(&self) -> Coord {
+    pub const fn to_coord(&self) -> Coord {
         match self {
             Dir::N => Coord(-1, 0),
             Dir::E => Coord(0, 1),
@@ -22,6 +26,166 @@ impl Dir {
     pub fn turn(&self, times: isize) -> Dir {
         Dir::from_repr((((*self as isize + times) % 4 + 4) % 4) as usize).unwrap()
     }
+
+    pub fn opposite(&self) -> Dir {
+        self.turn(2)
+    }
+
+    pub fn is_horizontal(&self) -> bool {
+        matches!(self, Dir::E | Dir::W)
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        matches!(self, Dir::N | Dir::S)
+    }
+
+    /// Returns the direction of the step from `a` to `b`, if they are orthogonally adjacent.
+    pub fn between(a: Coord, b: Coord) -> Option<Dir> {
+        Dir::iter().find(|dir| a + dir.to_coord() == b)
+    }
+
+    /// Parses an arrow (`^>v<`), a compass letter (`NESW`) or an `UDLR` letter.
+    pub fn from_char(c: char) -> Option<Dir> {
+        match c {
+            '^' | 'N' | 'U' => Some(Dir::N),
+            '>' | 'E' | 'R' => Some(Dir::E),
+            'v' | 'S' | 'D' => Some(Dir::S),
+            '<' | 'W' | 'L' => Some(Dir::W),
+            _ => None,
+        }
+    }
+
+    pub fn to_arrow(&self) -> char {
+        ['^', '>', 'v', '<'][*self as usize]
+    }
+
+    pub fn to_compass(&self) -> char {
+        ['N', 'E', 'S', 'W'][*self as usize]
+    }
+
+    pub fn to_udlr(&self) -> char {
+        ['U', 'R', 'D', 'L'][*self as usize]
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseDirError(String);
+
+impl Display for ParseDirError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "invalid direction {:?}", self.0)
+    }
+}
+
+impl Error for ParseDirError {}
+
+impl TryFrom<char> for Dir {
+    type Error = ParseDirError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        Dir::from_char(c).ok_or_else(|| ParseDirError(c.to_string()))
+    }
+}
+
+impl FromStr for Dir {
+    type Err = ParseDirError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next().and_then(Dir::from_char), chars.next()) {
+            (Some(dir), None) => Ok(dir),
+            _ => Err(ParseDirError(s.to_string())),
+        }
+    }
+}
+
+/// Formats as an arrow; the alternate flag `{:#}` formats as a compass letter.
+impl Display for Dir {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if f.alternate() {
+            write!(f, "{}", self.to_compass())
+        } else {
+            write!(f, "{}", self.to_arrow())
+        }
+    }
+}
+
+/// A set of directions stored as a bitmask.
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash, Debug)]
+pub struct DirSet(u8);
+
+impl DirSet {
+    pub const EMPTY: DirSet = DirSet(0);
+    pub const ALL: DirSet = DirSet(0b1111);
+
+    pub fn contains(&self, dir: Dir) -> bool {
+        self.0 & (1 << dir as u8) != 0
+    }
+
+    /// Adds `dir` and returns whether it was not yet present.
+    pub fn insert(&mut self, dir: Dir) -> bool {
+        let inserted = !self.contains(dir);
+        self.0 |= 1 << dir as u8;
+        inserted
+    }
+
+    /// Removes `dir` and returns whether it was present.
+    pub fn remove(&mut self, dir: Dir) -> bool {
+        let removed = self.contains(dir);
+        self.0 &= !(1 << dir as u8);
+        removed
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Dir> {
+        let set = *self;
+        Dir::iter().filter(move |&dir| set.contains(dir))
+    }
+}
+
+impl From<Dir> for DirSet {
+    fn from(dir: Dir) -> Self {
+        DirSet(1 << dir as u8)
+    }
+}
+
+impl FromIterator<Dir> for DirSet {
+    fn from_iter<I: IntoIterator<Item = Dir>>(iter: I) -> Self {
+        let mut set = DirSet::EMPTY;
+        for dir in iter {
+            set.insert(dir);
+        }
+        set
+    }
+}
+
+impl BitOr for DirSet {
+    type Output = DirSet;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        DirSet(self.0 | rhs.0)
+    }
+}
+
+impl BitOr<Dir> for DirSet {
+    type Output = DirSet;
+
+    fn bitor(self, rhs: Dir) -> Self::Output {
+        self | DirSet::from(rhs)
+    }
+}
+
+impl BitOrAssign<Dir> for DirSet {
+    fn bitor_assign(&mut self, rhs: Dir) {
+        self.insert(rhs);
+    }
 }
 
 #[cfg(test)]
@@ -41,4 +205,55 @@ mod tests {
         assert_eq!(Dir::S, Dir::N.turn(2));
         assert_eq!(Dir::S, Dir::N.turn(6));
     }
+
+    #[test]
+    fn test_axes() {
+        assert_eq!(Dir::S, Dir::N.opposite());
+        assert_eq!(Dir::E, Dir::W.opposite());
+        assert!(Dir::E.is_horizontal());
+        assert!(!Dir::E.is_vertical());
+        assert!(Dir::S.is_vertical());
+    }
+
+    #[test]
+    fn test_between() {
+        assert_eq!(Some(Dir::W), Dir::between(Coord(2, 2), Coord(2, 1)));
+        assert_eq!(Some(Dir::S), Dir::between(Coord(2, 2), Coord(3, 2)));
+        assert_eq!(None, Dir::between(Coord(2, 2), Coord(3, 3)));
+        assert_eq!(None, Dir::between(Coord(2, 2), Coord(2, 2)));
+    }
+
+    #[test]
+    fn test_parse_and_display() {
+        assert_eq!(
+            vec![Dir::N, Dir::E, Dir::S, Dir::W],
+            "^>v<".chars().map(|c| Dir::try_from(c).unwrap()).collect::<Vec<_>>()
+        );
+        assert_eq!(Ok(Dir::E), "E".parse());
+        assert_eq!(Ok(Dir::W), "L".parse());
+        assert!("x".parse::<Dir>().is_err());
+        assert!("NE".parse::<Dir>().is_err());
+        assert_eq!("invalid direction \"x\"", Dir::try_from('x').unwrap_err().to_string());
+
+        assert_eq!("v", Dir::S.to_string());
+        assert_eq!("S", format!("{:#}", Dir::S));
+        assert_eq!('D', Dir::S.to_udlr());
+    }
+
+    #[test]
+    fn test_dir_set() {
+        let mut set = DirSet::from(Dir::E);
+        assert!(set.insert(Dir::N));
+        assert!(!set.insert(Dir::E));
+        assert_eq!(vec![Dir::N, Dir::E], set.iter().collect::<Vec<_>>());
+        assert_eq!(2, set.len());
+
+        assert!(set.remove(Dir::N));
+        assert!(!set.contains(Dir::N));
+        assert_eq!(
+            DirSet::ALL,
+            set | Dir::N | [Dir::S, Dir::W].into_iter().collect::<DirSet>()
+        );
+        assert!(DirSet::EMPTY.is_empty());
+    }
 }
@@ -0,0 +1,166 @@
+use super::{Coord, Dir, Grid, Topology};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use strum::IntoEnumIterator;
+
+/// Shortest distances from a set of sources together with the direction back towards the nearest
+/// source from every reached cell.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DistanceField {
+    dist: Grid<Option<u32>>,
+    parents: Grid<Option<Dir>>,
+}
+
+impl DistanceField {
+    pub fn dist(&self) -> &Grid<Option<u32>> {
+        &self.dist
+    }
+
+    pub fn into_dist(self) -> Grid<Option<u32>> {
+        self.dist
+    }
+
+    /// Returns the direction of the step towards the nearest source, or `None` for sources and
+    /// unreached cells.
+    pub fn parent(&self, idx: Coord) -> Option<Dir> {
+        self.parents.get(idx).copied().flatten()
+    }
+
+    /// Returns a shortest path from `idx` back to its nearest source, or `None` if `idx` was not
+    /// reached.
+    pub fn path_from<G: Topology>(&self, topology: &G, idx: Coord) -> Option<Vec<Coord>> {
+        let mut idx = topology.resolve(idx)?;
+        self.dist[idx]?;
+
+        let mut path = vec![idx];
+        while let Some(dir) = self.parents[idx] {
+            idx = topology.resolve(idx + dir).unwrap();
+            path.push(idx);
+        }
+
+        Some(path)
+    }
+}
+
+pub(super) fn distances<G, I, F>(topology: &G, sources: I, mut passable: F) -> Grid<Option<u32>>
+where
+    G: Topology,
+    I: IntoIterator<Item = Coord>,
+    F: FnMut(Coord) -> bool,
+{
+    let mut dist = Grid::from_elem(topology.dim(), None);
+    let mut queue = VecDeque::new();
+
+    for source in sources.into_iter().filter_map(|source| topology.resolve(source)) {
+        if dist[source].is_none() {
+            dist[source] = Some(0);
+            queue.push_back(source);
+        }
+    }
+
+    while let Some(idx) = queue.pop_front() {
+        let next_dist = dist[idx].map(|d| d + 1);
+        for dir in Dir::iter() {
+            if let Some(next_idx) = topology.resolve(idx + dir) {
+                if dist[next_idx].is_none() && passable(next_idx) {
+                    dist[next_idx] = next_dist;
+                    queue.push_back(next_idx);
+                }
+            }
+        }
+    }
+
+    dist
+}
+
+pub(super) fn distance_field<G, I, F>(topology: &G, sources: I, mut cost: F) -> DistanceField
+where
+    G: Topology,
+    I: IntoIterator<Item = Coord>,
+    F: FnMut(Coord, Coord) -> Option<u32>,
+{
+    let mut dist = Grid::from_elem(topology.dim(), None);
+    let mut parents = Grid::from_elem(topology.dim(), None);
+    let mut heap = BinaryHeap::new();
+
+    for source in sources.into_iter().filter_map(|source| topology.resolve(source)) {
+        dist[source] = Some(0);
+        heap.push(Reverse((0, source)));
+    }
+
+    while let Some(Reverse((idx_dist, idx))) = heap.pop() {
+        if dist[idx] != Some(idx_dist) {
+            continue;
+        }
+
+        for dir in Dir::iter() {
+            let Some(next_idx) = topology.resolve(idx + dir) else {
+                continue;
+            };
+            let Some(step_cost) = cost(idx, next_idx) else {
+                continue;
+            };
+
+            let next_dist = idx_dist + step_cost;
+            if dist[next_idx].is_none_or(|d| next_dist < d) {
+                dist[next_idx] = Some(next_dist);
+                parents[next_idx] = Some(dir.opposite());
+                heap.push(Reverse((next_dist, next_idx)));
+            }
+        }
+    }
+
+    DistanceField { dist, parents }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::grid::TorusGrid;
+
+    #[test]
+    fn test_distances() {
+        let grid: Grid<char> = Grid::from_str("S.#\n.##\n..S\n");
+
+        let dist = grid.distances([Coord(0, 0), Coord(2, 2)], |idx| grid[idx] != '#');
+        assert_eq!(
+            vec![Some(0), Some(1), None, Some(1), None, None, Some(2), Some(1), Some(0)],
+            dist.iter().copied().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_distances_torus() {
+        let torus = TorusGrid::from_elem(Coord(1, 5), ());
+
+        let dist = torus.distances([Coord(0, 0)], |_| true);
+        assert_eq!(
+            vec![Some(0), Some(1), Some(2), Some(2), Some(1)],
+            dist.iter().copied().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_weighted_distances() {
+        let grid = Grid::from_vec(Coord(2, 3), vec![1, 9, 1, 1, 1, 1]);
+
+        let dist = grid.weighted_distances([Coord(0, 0)], |_, to| Some(grid[to]));
+        assert_eq!(Some(4), dist[Coord(0, 2)]);
+        assert_eq!(Some(9), dist[Coord(0, 1)]);
+    }
+
+    #[test]
+    fn test_distance_field_path() {
+        let grid: Grid<char> = Grid::from_str("...\n##.\n...\n");
+
+        let field = grid.distance_field([Coord(0, 0)], |_, to| (grid[to] == '.').then_some(1));
+        assert_eq!(Some(6), field.dist()[Coord(2, 0)]);
+        assert_eq!(Some(Dir::E), field.parent(Coord(2, 0)));
+        assert_eq!(None, field.parent(Coord(0, 0)));
+        assert_eq!(
+            Some(vec![Coord(1, 2), Coord(0, 2), Coord(0, 1), Coord(0, 0)]),
+            field.path_from(&grid, Coord(1, 2))
+        );
+        assert_eq!(None, field.path_from(&grid, Coord(1, 0)));
+    }
+}
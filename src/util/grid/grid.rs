@@ -1,8 +1,41 @@
-use super::Coord;
-use std::fmt::{Debug, Display, Formatter};
+use super::{Coord, Dir};
+use std::fmt::Debug;
+use std::iter::successors;
 use std::ops::{Index, IndexMut};
 use std::str::FromStr;
 
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Connectivity {
+    Four,
+    Eight,
+}
+
+impl Connectivity {
+    pub fn offsets(&self) -> &'static [Coord] {
+        const FOUR: [Coord; 4] = [
+            Dir::N.to_coord(),
+            Dir::E.to_coord(),
+            Dir::S.to_coord(),
+            Dir::W.to_coord(),
+        ];
+        const EIGHT: [Coord; 8] = [
+            Coord(-1, 0),
+            Coord(-1, 1),
+            Coord(0, 1),
+            Coord(1, 1),
+            Coord(1, 0),
+            Coord(1, -1),
+            Coord(0, -1),
+            Coord(-1, -1),
+        ];
+
+        match self {
+            Connectivity::Four => &FOUR,
+            Connectivity::Eight => &EIGHT,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Grid<T> {
     dim: Coord,
@@ -19,7 +52,10 @@ impl<T> Grid<T> {
         }
 
         let len = dim.0.checked_mul(dim.1).unwrap() as usize;
-        Grid::<T> { dim, vec: vec![elem; len]}
+        Grid::<T> {
+            dim,
+            vec: vec![elem; len],
+        }
     }
 
     pub fn from_vec(dim: Coord, vec: Vec<T>) -> Self {
@@ -87,14 +123,72 @@ impl<T> Grid<T> {
         self.vec.iter_mut()
     }
 
+    /// Returns the cells in row-major order.
+    pub fn as_slice(&self) -> &[T] {
+        &self.vec
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.vec
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.vec
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid::from_vec(self.dim, self.vec.iter().map(f).collect())
+    }
+
     pub fn indexed_iter(&self) -> impl Iterator<Item = (Coord, &T)> {
-        (0..self.dim.0)
-            .into_iter()
-            .flat_map(|row| (0..self.dim.1).into_iter().map(move |col| Coord(row, col)))
-            .zip(self.vec.iter())
+        self.indices().zip(self.vec.iter())
+    }
+
+    pub fn into_indexed_iter(self) -> impl Iterator<Item = (Coord, T)> {
+        self.indices().zip(self.vec)
+    }
+
+    pub fn indices(&self) -> impl Iterator<Item = Coord> {
+        let dim = self.dim;
+        (0..dim.0).flat_map(move |row| (0..dim.1).map(move |col| Coord(row, col)))
+    }
+
+    pub fn neighbours(&self, idx: Coord, connectivity: Connectivity) -> impl Iterator<Item = (Coord, &T)> {
+        connectivity
+            .offsets()
+            .iter()
+            .filter_map(move |&offset| self.get(idx + offset).map(|elem| (idx + offset, elem)))
+    }
+
+    /// Walks from `start` in increments of `step` until leaving the grid, starting with `start`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is `Coord(0, 0)`, as the walk would never leave the grid.
+    pub fn ray(&self, start: Coord, step: Coord) -> impl Iterator<Item = (Coord, &T)> {
+        if step == Coord(0, 0) {
+            panic!("step == 0");
+        }
+
+        successors(Some(start), move |&idx| Some(idx + step)).map_while(|idx| self.get(idx).map(|elem| (idx, elem)))
     }
 
-    fn is_in_bounds(&self, idx: Coord) -> bool {
+    /// Like [`Grid::ray`], but also stops at the first cell for which `pred` does not hold.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is `Coord(0, 0)`.
+    pub fn ray_while<F>(&self, start: Coord, step: Coord, mut pred: F) -> impl Iterator<Item = (Coord, &T)>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.ray(start, step).take_while(move |(_, elem)| pred(elem))
+    }
+
+    pub fn is_in_bounds(&self, idx: Coord) -> bool {
         idx.0 >= 0 && idx.1 >= 0 && idx.0 < self.dim.0 && idx.1 < self.dim.1
     }
 }
@@ -126,32 +220,15 @@ impl<T> IndexMut<Coord> for Grid<T> {
     }
 }*/
 
-impl Display for Grid<bool> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        for row in 0..self.dim.0 {
-            for col in 0..self.dim.1 {
-                if self[Coord(row, col)] {
-                    write!(f, "#")?
-                } else {
-                    write!(f, ".")?
-                }
-            }
-            f.write_str("\n")?
-        }
-
-        Ok(())
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn test_from_elem() {
-        let grid = Grid::from_elem(Coord(2,3), 8);
-        assert_eq!(8, grid[Coord(1,1)]);
-        assert_eq!(8, grid[Coord(1,2)]);
+        let grid = Grid::from_elem(Coord(2, 3), 8);
+        assert_eq!(8, grid[Coord(1, 1)]);
+        assert_eq!(8, grid[Coord(1, 2)]);
     }
 
     #[test]
@@ -202,4 +279,32 @@ mod tests {
 
         assert_eq!(None, iter.next());
     }
+
+    #[test]
+    fn test_ray() {
+        let grid = Grid::from_vec(Coord(3, 3), vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        let ray = grid.ray(Coord(0, 0), Coord(1, 1)).collect::<Vec<_>>();
+        assert_eq!(vec![(Coord(0, 0), &1), (Coord(1, 1), &5), (Coord(2, 2), &9)], ray);
+
+        let ray = grid
+            .ray(Coord(2, 0), Coord(-1, 2))
+            .map(|(_, &elem)| elem)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![7, 6], ray);
+
+        assert_eq!(0, grid.ray(Coord(3, 0), Coord(-1, 0)).count());
+
+        let ray = grid
+            .ray_while(Coord(0, 2), Coord(1, 0), |&elem| elem < 9)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![(Coord(0, 2), &3), (Coord(1, 2), &6)], ray);
+    }
+
+    #[test]
+    #[should_panic(expected = "step == 0")]
+    fn test_ray_zero_step() {
+        let grid = Grid::from_vec(Coord(1, 1), vec![1]);
+        let _ = grid.ray(Coord(0, 0), Coord(0, 0));
+    }
 }
@@ -0,0 +1,116 @@
+use super::{Coord, Grid};
+use std::fs::{create_dir_all, File};
+use std::io::{BufWriter, Result, Write};
+use std::path::Path;
+
+impl<T> Grid<T> {
+    /// Writes the grid as binary PGM, mapping every cell to a grey level with `f` and drawing it
+    /// as a `scale` x `scale` block of pixels.
+    pub fn write_pgm<W, F>(&self, w: W, scale: usize, f: F) -> Result<()>
+    where
+        W: Write,
+        F: Fn(&T) -> u8,
+    {
+        self.write_netpbm(w, "P5", scale, |elem| [f(elem)])
+    }
+
+    /// Writes the grid as binary PPM, mapping every cell to an RGB colour with `f` and drawing it
+    /// as a `scale` x `scale` block of pixels.
+    pub fn write_ppm<W, F>(&self, w: W, scale: usize, f: F) -> Result<()>
+    where
+        W: Write,
+        F: Fn(&T) -> [u8; 3],
+    {
+        self.write_netpbm(w, "P6", scale, f)
+    }
+
+    /// Like [`Grid::write_pgm`], but creates the file at `path` along with its parent directories.
+    pub fn save_pgm<P, F>(&self, path: P, scale: usize, f: F) -> Result<()>
+    where
+        P: AsRef<Path>,
+        F: Fn(&T) -> u8,
+    {
+        self.write_pgm(create_file(path.as_ref())?, scale, f)
+    }
+
+    /// Like [`Grid::write_ppm`], but creates the file at `path` along with its parent directories.
+    pub fn save_ppm<P, F>(&self, path: P, scale: usize, f: F) -> Result<()>
+    where
+        P: AsRef<Path>,
+        F: Fn(&T) -> [u8; 3],
+    {
+        self.write_ppm(create_file(path.as_ref())?, scale, f)
+    }
+
+    fn write_netpbm<W, F, const N: usize>(&self, mut w: W, magic: &str, scale: usize, f: F) -> Result<()>
+    where
+        W: Write,
+        F: Fn(&T) -> [u8; N],
+    {
+        let dim = self.dim();
+        let (rows, cols) = (dim.0 as usize, dim.1 as usize);
+        write!(w, "{}\n{} {}\n255\n", magic, cols * scale, rows * scale)?;
+
+        let mut line = Vec::with_capacity(cols * scale * N);
+        for row in 0..dim.0 {
+            line.clear();
+            for col in 0..dim.1 {
+                let pixel = f(&self[Coord(row, col)]);
+                for _ in 0..scale {
+                    line.extend_from_slice(&pixel);
+                }
+            }
+
+            for _ in 0..scale {
+                w.write_all(&line)?;
+            }
+        }
+
+        w.flush()
+    }
+}
+
+fn create_file(path: &Path) -> Result<BufWriter<File>> {
+    if let Some(parent) = path.parent() {
+        create_dir_all(parent)?;
+    }
+
+    Ok(BufWriter::new(File::create(path)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_pgm() {
+        let grid = Grid::from_vec(Coord(2, 3), vec![0, 1, 2, 3, 4, 5]);
+
+        let mut buf = Vec::new();
+        grid.write_pgm(&mut buf, 1, |&elem| elem * 50).unwrap();
+        assert_eq!(b"P5\n3 2\n255\n\x00\x32\x64\x96\xc8\xfa", buf.as_slice());
+    }
+
+    #[test]
+    fn test_write_ppm_scaled() {
+        let grid = Grid::from_vec(Coord(1, 2), vec![true, false]);
+
+        let mut buf = Vec::new();
+        grid.write_ppm(&mut buf, 2, |&elem| if elem { [255, 0, 0] } else { [0, 0, 0] })
+            .unwrap();
+
+        let header = b"P6\n4 2\n255\n";
+        let line = [255, 0, 0, 255, 0, 0, 0, 0, 0, 0, 0, 0];
+        assert_eq!(header, &buf[..header.len()]);
+        assert_eq!([line, line].concat(), &buf[header.len()..]);
+    }
+
+    #[test]
+    fn test_save_pgm() {
+        let path = std::env::temp_dir().join("aoc2024_test_save_pgm").join("grid.pgm");
+        let grid = Grid::from_elem(Coord(2, 2), 7u8);
+
+        grid.save_pgm(&path, 3, |&elem| elem).unwrap();
+        assert_eq!(11 + 36, std::fs::metadata(&path).unwrap().len());
+    }
+}
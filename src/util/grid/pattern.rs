@@ -0,0 +1,184 @@
+use super::{Coord, Grid, Rect};
+
+/// One of the 8 rotations and reflections of a square.
+///
+/// The template is first mirrored left to right if `reflected` is set, and then rotated clockwise
+/// by `quarter_turns` quarter turns.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Orientation {
+    pub quarter_turns: u8,
+    pub reflected: bool,
+}
+
+impl Orientation {
+    pub const IDENTITY: Orientation = Orientation {
+        quarter_turns: 0,
+        reflected: false,
+    };
+
+    pub const ALL: [Orientation; 8] = {
+        let mut all = [Orientation::IDENTITY; 8];
+        let mut i = 0;
+        while i < 8 {
+            all[i] = Orientation {
+                quarter_turns: (i % 4) as u8,
+                reflected: i >= 4,
+            };
+            i += 1;
+        }
+        all
+    };
+
+    /// Maps `idx` of a template with dimension `dim` into the oriented template.
+    fn apply(&self, dim: Coord, idx: Coord) -> Coord {
+        let mut dim = dim;
+        let mut idx = if self.reflected {
+            Coord(idx.0, dim.1 - 1 - idx.1)
+        } else {
+            idx
+        };
+
+        for _ in 0..self.quarter_turns {
+            idx = Coord(idx.1, dim.0 - 1 - idx.0);
+            dim = Coord(dim.1, dim.0);
+        }
+
+        idx
+    }
+}
+
+/// A small template grid in which `None` cells match anything.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Pattern<T> {
+    grid: Grid<Option<T>>,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct PatternMatch {
+    /// Top-left corner of the oriented template within the searched grid.
+    pub pos: Coord,
+    pub orientation: Orientation,
+}
+
+impl<T> Pattern<T> {
+    pub fn new(grid: Grid<Option<T>>) -> Self {
+        Pattern { grid }
+    }
+
+    pub fn dim(&self) -> Coord {
+        self.grid.dim()
+    }
+
+    fn oriented(&self, orientation: Orientation) -> Vec<(Coord, &T)> {
+        let mut cells = self
+            .grid
+            .indexed_iter()
+            .filter_map(|(idx, elem)| elem.as_ref().map(|elem| (orientation.apply(self.dim(), idx), elem)))
+            .collect::<Vec<_>>();
+        cells.sort_by_key(|&(idx, _)| idx);
+        cells
+    }
+}
+
+impl Pattern<char> {
+    /// Parses a character template, treating every `wildcard` as a wildcard cell.
+    pub fn from_str(s: &str, wildcard: char) -> Self {
+        let grid: Grid<char> = Grid::from_str(s);
+        Pattern::new(grid.map(|&c| if c == wildcard { None } else { Some(c) }))
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// Finds every occurrence of `pattern` under any of the given `orientations`.
+    ///
+    /// Orientations under which the template looks the same as under an earlier one are skipped,
+    /// so that every placement of a symmetric template is reported once.
+    pub fn find_pattern(&self, pattern: &Pattern<T>, orientations: &[Orientation]) -> Vec<PatternMatch> {
+        let mut matches = vec![];
+        let mut searched: Vec<(Coord, Vec<(Coord, &T)>)> = vec![];
+
+        for &orientation in orientations {
+            let dim = if orientation.quarter_turns % 2 == 0 {
+                pattern.dim()
+            } else {
+                Coord(pattern.dim().1, pattern.dim().0)
+            };
+
+            // Wildcard borders can make templates of different shape share their cells.
+            let template = (dim, pattern.oriented(orientation));
+            if searched.contains(&template) {
+                continue;
+            }
+
+            for pos in Rect::from_dim(self.dim() - dim + Coord(1, 1)).iter() {
+                if template.1.iter().all(|&(offset, elem)| &self[pos + offset] == elem) {
+                    matches.push(PatternMatch { pos, orientation });
+                }
+            }
+
+            searched.push(template);
+        }
+
+        matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_orientation() {
+        let dim = Coord(2, 3);
+
+        assert_eq!(Coord(0, 1), Orientation::IDENTITY.apply(dim, Coord(0, 1)));
+        assert_eq!(Coord(1, 1), Orientation::ALL[1].apply(dim, Coord(0, 1)));
+        assert_eq!(Coord(1, 1), Orientation::ALL[2].apply(dim, Coord(0, 1)));
+        assert_eq!(Coord(2, 0), Orientation::ALL[3].apply(dim, Coord(0, 0)));
+        assert_eq!(Coord(0, 2), Orientation::ALL[4].apply(dim, Coord(0, 0)));
+        assert_eq!(Coord(2, 1), Orientation::ALL[5].apply(dim, Coord(0, 0)));
+    }
+
+    #[test]
+    fn test_find_pattern() {
+        let grid: Grid<char> = Grid::from_str("ABX\nXBA\nABA\n");
+
+        let matches = grid.find_pattern(&Pattern::from_str("AB", '.'), &[Orientation::IDENTITY]);
+        assert_eq!(
+            vec![Coord(0, 0), Coord(2, 0)],
+            matches.iter().map(|m| m.pos).collect::<Vec<_>>()
+        );
+
+        let matches = grid.find_pattern(&Pattern::from_str("AB", '.'), &Orientation::ALL);
+        assert_eq!(4, matches.len());
+        assert!(matches.contains(&PatternMatch {
+            pos: Coord(1, 1),
+            orientation: Orientation::ALL[2],
+        }));
+    }
+
+    #[test]
+    fn test_find_pattern_wildcards() {
+        let grid: Grid<char> = Grid::from_str("ABA\nXBX\nAXA\n");
+
+        let matches = grid.find_pattern(&Pattern::from_str("A.A\n.B.", '.'), &Orientation::ALL);
+        let expected =
+            [(Coord(0, 0), 0), (Coord(0, 1), 1), (Coord(1, 0), 2), (Coord(0, 0), 3)].map(|(pos, i)| PatternMatch {
+                pos,
+                orientation: Orientation::ALL[i],
+            });
+        assert_eq!(expected.to_vec(), matches);
+    }
+
+    #[test]
+    fn test_find_pattern_wildcard_border() {
+        let grid: Grid<char> = Grid::from_str("AX\nXX\n");
+
+        let matches = grid.find_pattern(&Pattern::from_str("A.", '.'), &Orientation::ALL[..2]);
+        let expected = [0, 1].map(|i| PatternMatch {
+            pos: Coord(0, 0),
+            orientation: Orientation::ALL[i],
+        });
+        assert_eq!(expected.to_vec(), matches);
+    }
+}
@@ -0,0 +1,229 @@
+use super::{Coord, Grid};
+use std::fs::{create_dir_all, File};
+use std::io::{stdout, BufWriter, Result, Write};
+use std::path::Path;
+use std::thread::sleep;
+use std::time::Duration;
+
+/// ANSI terminal colour of a rendered cell.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Colour {
+    Black = 30,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+}
+
+/// Records grid snapshots of a step simulation as text frames with ANSI colours.
+///
+/// Simulations call [`FrameRecorder::record`] once per step. Only every `frame_skip + 1`th step is
+/// kept, and recording stops silently once `max_frames` frames have been kept.
+#[derive(Clone, Debug)]
+pub struct FrameRecorder {
+    frame_skip: usize,
+    max_frames: usize,
+    steps: usize,
+    dim: Coord,
+    frames: Vec<String>,
+}
+
+impl FrameRecorder {
+    pub fn new() -> Self {
+        FrameRecorder {
+            frame_skip: 0,
+            max_frames: usize::MAX,
+            steps: 0,
+            dim: Coord(0, 0),
+            frames: vec![],
+        }
+    }
+
+    pub fn with_frame_skip(self, frame_skip: usize) -> Self {
+        FrameRecorder { frame_skip, ..self }
+    }
+
+    pub fn with_max_frames(self, max_frames: usize) -> Self {
+        FrameRecorder { max_frames, ..self }
+    }
+
+    pub fn frames(&self) -> &[String] {
+        &self.frames
+    }
+
+    /// Renders `grid` as the next frame, mapping every cell to a character and optional colour.
+    pub fn record<T, F>(&mut self, grid: &Grid<T>, f: F)
+    where
+        F: Fn(&T) -> (char, Option<Colour>),
+    {
+        let step = self.steps;
+        self.steps += 1;
+
+        if !step.is_multiple_of(self.frame_skip + 1) || self.frames.len() >= self.max_frames {
+            return;
+        }
+
+        let dim = grid.dim();
+        let mut frame = String::new();
+        let mut current_colour = None;
+
+        for row in 0..dim.0 {
+            for col in 0..dim.1 {
+                let (c, colour) = f(&grid[Coord(row, col)]);
+                if colour != current_colour {
+                    match colour {
+                        Some(colour) => frame += &format!("\x1b[{}m", colour as u8),
+                        None => frame += "\x1b[0m",
+                    }
+                    current_colour = colour;
+                }
+                frame.push(c);
+            }
+
+            if current_colour.is_some() {
+                frame += "\x1b[0m";
+                current_colour = None;
+            }
+            frame += "\r\n";
+        }
+
+        self.dim = Coord(self.dim.0.max(dim.0), self.dim.1.max(dim.1));
+        self.frames.push(frame);
+    }
+
+    /// Writes all frames as an asciicast v2 recording, showing each for `frame_duration`.
+    pub fn write_asciicast<W: Write>(&self, mut w: W, frame_duration: Duration) -> Result<()> {
+        writeln!(
+            w,
+            r#"{{"version": 2, "width": {}, "height": {}}}"#,
+            self.dim.1, self.dim.0
+        )?;
+
+        for (i, frame) in self.frames.iter().enumerate() {
+            let time = frame_duration.as_secs_f64() * i as f64;
+            writeln!(
+                w,
+                r#"[{:.6}, "o", "{}"]"#,
+                time,
+                escape_json(&format!("\x1b[H\x1b[2J{}", frame))
+            )?;
+        }
+
+        w.flush()
+    }
+
+    /// Like [`FrameRecorder::write_asciicast`], but creates the file at `path` along with its
+    /// parent directories.
+    pub fn save_asciicast<P: AsRef<Path>>(&self, path: P, frame_duration: Duration) -> Result<()> {
+        if let Some(parent) = path.as_ref().parent() {
+            create_dir_all(parent)?;
+        }
+
+        self.write_asciicast(BufWriter::new(File::create(path)?), frame_duration)
+    }
+
+    /// Plays all frames back on the terminal, showing each for `frame_duration`.
+    pub fn replay(&self, frame_duration: Duration) -> Result<()> {
+        let mut stdout = stdout().lock();
+
+        for frame in &self.frames {
+            write!(stdout, "\x1b[H\x1b[2J{}", frame)?;
+            stdout.flush()?;
+            sleep(frame_duration);
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for FrameRecorder {
+    fn default() -> Self {
+        FrameRecorder::new()
+    }
+}
+
+fn escape_json(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+
+    for c in s.chars() {
+        match c {
+            '"' => escaped += "\\\"",
+            '\\' => escaped += "\\\\",
+            '\n' => escaped += "\\n",
+            '\r' => escaped += "\\r",
+            c if (c as u32) < 0x20 => escaped += &format!("\\u{:04x}", c as u32),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::grid::{Dir, Topology};
+
+    #[test]
+    fn test_record() {
+        let mut recorder = FrameRecorder::new().with_frame_skip(1).with_max_frames(2);
+        let mut grid = Grid::from_elem(Coord(1, 3), false);
+
+        for col in 0..3 {
+            grid[Coord(0, col)] = true;
+            recorder.record(&grid, |&elem| if elem { ('#', Some(Colour::Red)) } else { ('.', None) });
+        }
+        recorder.record(&grid, |_| ('?', None));
+
+        assert_eq!(
+            vec!["\x1b[31m#\x1b[0m..\r\n", "\x1b[31m###\x1b[0m\r\n"],
+            recorder.frames()
+        );
+    }
+
+    #[test]
+    fn test_write_asciicast() {
+        let mut recorder = FrameRecorder::new();
+        recorder.record(&Grid::from_vec(Coord(2, 1), vec!['"', 'x']), |&c| (c, None));
+
+        let mut buf = Vec::new();
+        recorder.write_asciicast(&mut buf, Duration::from_millis(100)).unwrap();
+
+        assert_eq!(
+            "{\"version\": 2, \"width\": 1, \"height\": 2}\n\
+             [0.000000, \"o\", \"\\u001b[H\\u001b[2J\\\"\\r\\nx\\r\\n\"]\n",
+            String::from_utf8(buf).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_record_walk() {
+        let grid: Grid<char> = Grid::from_str("...\n.#.\n...\n");
+        let mut recorder = FrameRecorder::new();
+
+        let walk = grid.walk(Coord(2, 0), Dir::N, |idx, mut dir| {
+            while grid.get(idx + dir) == Some(&'#') {
+                dir = dir.turn(1);
+            }
+
+            let mut frame = grid.clone();
+            frame[idx] = dir.to_arrow();
+            recorder.record(&frame, |&c| match c {
+                '#' => (c, Some(Colour::Blue)),
+                '.' => (c, None),
+                _ => (c, Some(Colour::Yellow)),
+            });
+            dir
+        });
+
+        assert_eq!(walk.path.len(), recorder.frames().len());
+        assert_eq!("...\r\n\x1b[33m^\x1b[34m#\x1b[0m.\r\n...\r\n", recorder.frames()[1]);
+        assert_eq!(
+            "\x1b[33m^\x1b[0m..\r\n.\x1b[34m#\x1b[0m.\r\n...\r\n",
+            recorder.frames()[2]
+        );
+    }
+}
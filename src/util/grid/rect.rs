@@ -0,0 +1,206 @@
+use super::{Coord, Grid};
+
+/// The half-open rectangle of coordinates from `min` (inclusive) to `max` (exclusive).
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Rect {
+    pub min: Coord,
+    pub max: Coord,
+}
+
+impl Rect {
+    pub fn new(min: Coord, max: Coord) -> Self {
+        Rect { min, max }
+    }
+
+    pub fn from_dim(dim: Coord) -> Self {
+        Rect::new(Coord(0, 0), dim)
+    }
+
+    /// Returns the smallest rectangle containing both `a` and `b`.
+    pub fn spanning(a: Coord, b: Coord) -> Self {
+        Rect::new(
+            Coord(a.0.min(b.0), a.1.min(b.1)),
+            Coord(a.0.max(b.0) + 1, a.1.max(b.1) + 1),
+        )
+    }
+
+    pub fn dim(&self) -> Coord {
+        Coord((self.max.0 - self.min.0).max(0), (self.max.1 - self.min.1).max(0))
+    }
+
+    pub fn area(&self) -> usize {
+        let dim = self.dim();
+        (dim.0 * dim.1) as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.area() == 0
+    }
+
+    pub fn contains(&self, idx: Coord) -> bool {
+        idx.0 >= self.min.0 && idx.1 >= self.min.1 && idx.0 < self.max.0 && idx.1 < self.max.1
+    }
+
+    pub fn contains_rect(&self, other: &Rect) -> bool {
+        other.is_empty()
+            || other.min.0 >= self.min.0
+                && other.min.1 >= self.min.1
+                && other.max.0 <= self.max.0
+                && other.max.1 <= self.max.1
+    }
+
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let rect = Rect::new(
+            Coord(self.min.0.max(other.min.0), self.min.1.max(other.min.1)),
+            Coord(self.max.0.min(other.max.0), self.max.1.min(other.max.1)),
+        );
+
+        if rect.is_empty() {
+            None
+        } else {
+            Some(rect)
+        }
+    }
+
+    /// Returns the smallest rectangle containing this rectangle and `idx`.
+    pub fn extend(&self, idx: Coord) -> Rect {
+        if self.is_empty() {
+            return Rect::spanning(idx, idx);
+        }
+
+        Rect::new(
+            Coord(self.min.0.min(idx.0), self.min.1.min(idx.1)),
+            Coord(self.max.0.max(idx.0 + 1), self.max.1.max(idx.1 + 1)),
+        )
+    }
+
+    /// Splits into the top-left, top-right, bottom-left and bottom-right quadrant.
+    ///
+    /// The row and column at `min + dim / 2` belong to no quadrant.
+    pub fn quadrants(&self) -> [Rect; 4] {
+        let mid = self.min + Coord(self.dim().0 / 2, self.dim().1 / 2);
+
+        [
+            Rect::new(self.min, mid),
+            Rect::new(Coord(self.min.0, mid.1 + 1), Coord(mid.0, self.max.1)),
+            Rect::new(Coord(mid.0 + 1, self.min.1), Coord(self.max.0, mid.1)),
+            Rect::new(mid + Coord(1, 1), self.max),
+        ]
+    }
+
+    /// Iterates over all coordinates in row-major order.
+    pub fn iter(&self) -> RectIter {
+        RectIter {
+            rect: *self,
+            next: if self.is_empty() { None } else { Some(self.min) },
+        }
+    }
+}
+
+impl IntoIterator for Rect {
+    type Item = Coord;
+    type IntoIter = RectIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RectIter {
+    rect: Rect,
+    next: Option<Coord>,
+}
+
+impl Iterator for RectIter {
+    type Item = Coord;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.next?;
+
+        self.next = if idx.1 + 1 < self.rect.max.1 {
+            Some(Coord(idx.0, idx.1 + 1))
+        } else if idx.0 + 1 < self.rect.max.0 {
+            Some(Coord(idx.0 + 1, self.rect.min.1))
+        } else {
+            None
+        };
+
+        Some(idx)
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn rect(&self) -> Rect {
+        Rect::from_dim(self.dim())
+    }
+
+    /// Iterates over the cells of `rect` that lie within the grid in row-major order.
+    pub fn rect_iter(&self, rect: Rect) -> impl Iterator<Item = (Coord, &T)> {
+        rect.intersection(&self.rect())
+            .into_iter()
+            .flat_map(|rect| rect.iter())
+            .map(|idx| (idx, &self[idx]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iter() {
+        let mut iter = Rect::new(Coord(1, 2), Coord(3, 4)).iter();
+
+        assert_eq!(Some(Coord(1, 2)), iter.next());
+        assert_eq!(Some(Coord(1, 3)), iter.next());
+        assert_eq!(Some(Coord(2, 2)), iter.next());
+        assert_eq!(Some(Coord(2, 3)), iter.next());
+        assert_eq!(None, iter.next());
+
+        assert_eq!(0, Rect::new(Coord(1, 2), Coord(0, 4)).iter().count());
+    }
+
+    #[test]
+    fn test_contains_and_intersection() {
+        let rect = Rect::new(Coord(0, 0), Coord(3, 4));
+
+        assert!(rect.contains(Coord(2, 3)));
+        assert!(!rect.contains(Coord(3, 3)));
+        assert!(rect.contains_rect(&Rect::new(Coord(1, 1), Coord(3, 4))));
+        assert!(!rect.contains_rect(&Rect::new(Coord(-1, 1), Coord(3, 4))));
+
+        assert_eq!(
+            Some(Rect::new(Coord(2, 1), Coord(3, 4))),
+            rect.intersection(&Rect::new(Coord(2, 1), Coord(5, 5)))
+        );
+        assert_eq!(None, rect.intersection(&Rect::new(Coord(3, 0), Coord(5, 5))));
+
+        assert_eq!(Rect::new(Coord(-1, 0), Coord(3, 4)), rect.extend(Coord(-1, 2)));
+        assert_eq!(
+            Rect::new(Coord(0, 1), Coord(3, 6)),
+            Rect::spanning(Coord(2, 5), Coord(0, 1))
+        );
+    }
+
+    #[test]
+    fn test_quadrants() {
+        let quadrants = Rect::from_dim(Coord(7, 11)).quadrants();
+
+        assert_eq!(Rect::new(Coord(0, 0), Coord(3, 5)), quadrants[0]);
+        assert_eq!(Rect::new(Coord(0, 6), Coord(3, 11)), quadrants[1]);
+        assert_eq!(Rect::new(Coord(4, 0), Coord(7, 5)), quadrants[2]);
+        assert_eq!(Rect::new(Coord(4, 6), Coord(7, 11)), quadrants[3]);
+    }
+
+    #[test]
+    fn test_grid_rect_iter() {
+        let grid = Grid::from_vec(Coord(2, 3), vec![1, 2, 3, 4, 5, 6]);
+
+        let elems = grid
+            .rect_iter(Rect::new(Coord(-1, 1), Coord(5, 3)))
+            .map(|(_, &elem)| elem)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![2, 3, 5, 6], elems);
+    }
+}
@@ -0,0 +1,206 @@
+use super::{Connectivity, Coord, Grid, Rect};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Regions {
+    connectivity: Connectivity,
+    labels: Grid<usize>,
+    count: usize,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct RegionMetrics {
+    pub area: usize,
+    pub perimeter: usize,
+    pub sides: usize,
+    pub bounding_box: Rect,
+    pub holes: usize,
+}
+
+impl<T> Grid<T> {
+    /// Labels the connected components of cells for which `eq` holds between neighbours.
+    ///
+    /// Labels are assigned in row-major order of the first cell of each region, starting at 0.
+    pub fn label_regions<F>(&self, connectivity: Connectivity, eq: F) -> Regions
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        const UNLABELLED: usize = usize::MAX;
+
+        let mut labels = Grid::from_elem(self.dim(), UNLABELLED);
+        let mut count = 0;
+        let mut stack = Vec::new();
+
+        for (idx, _) in self.indexed_iter() {
+            if labels[idx] != UNLABELLED {
+                continue;
+            }
+
+            labels[idx] = count;
+            stack.push(idx);
+
+            while let Some(idx) = stack.pop() {
+                for (next_idx, next_elem) in self.neighbours(idx, connectivity) {
+                    if labels[next_idx] == UNLABELLED && eq(&self[idx], next_elem) {
+                        labels[next_idx] = count;
+                        stack.push(next_idx);
+                    }
+                }
+            }
+
+            count += 1;
+        }
+
+        Regions {
+            connectivity,
+            labels,
+            count,
+        }
+    }
+}
+
+impl Regions {
+    pub fn labels(&self) -> &Grid<usize> {
+        &self.labels
+    }
+
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    pub fn label(&self, idx: Coord) -> Option<usize> {
+        self.labels.get(idx).copied()
+    }
+
+    pub fn metrics(&self) -> Vec<RegionMetrics> {
+        let dim = self.labels.dim();
+
+        let mut metrics = vec![
+            RegionMetrics {
+                area: 0,
+                perimeter: 0,
+                sides: 0,
+                bounding_box: Rect::new(Coord(0, 0), Coord(0, 0)),
+                holes: 0,
+            };
+            self.count
+        ];
+
+        for (idx, &label) in self.labels.indexed_iter() {
+            let m = &mut metrics[label];
+            m.area += 1;
+            m.bounding_box = m.bounding_box.extend(idx);
+
+            for offset in Connectivity::Four.offsets() {
+                if self.label(idx + *offset) != Some(label) {
+                    m.perimeter += 1;
+                }
+            }
+        }
+
+        // Every 2x2 window ("bit quad") is classified per region it touches. Corners of the region
+        // outline are counted for the sides, and Gray's bit quad counts yield the Euler number.
+        let mut euler_quads = vec![0isize; self.count];
+
+        for row in -1..dim.0 {
+            for col in -1..dim.1 {
+                let quad = [
+                    self.label(Coord(row, col)),
+                    self.label(Coord(row, col + 1)),
+                    self.label(Coord(row + 1, col)),
+                    self.label(Coord(row + 1, col + 1)),
+                ];
+
+                for (i, label) in quad.iter().enumerate() {
+                    let Some(label) = *label else { continue };
+                    if quad[..i].contains(&Some(label)) {
+                        continue;
+                    }
+
+                    let mask = quad.map(|l| l == Some(label));
+                    let set = mask.iter().filter(|&&b| b).count();
+                    let diagonal = set == 2 && mask[0] == mask[3];
+
+                    match (set, diagonal) {
+                        (1, _) => {
+                            metrics[label].sides += 1;
+                            euler_quads[label] += 1;
+                        }
+                        (3, _) => {
+                            metrics[label].sides += 1;
+                            euler_quads[label] -= 1;
+                        }
+                        (2, true) => {
+                            metrics[label].sides += 2;
+                            euler_quads[label] += match self.connectivity {
+                                Connectivity::Four => 2,
+                                Connectivity::Eight => -2,
+                            };
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        for (m, euler_quad) in metrics.iter_mut().zip(euler_quads) {
+            m.holes = (1 - euler_quad / 4) as usize;
+        }
+
+        metrics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_label_regions() {
+        let grid: Grid<char> = Grid::from_str("AAB\nABB\nCAC\n");
+
+        let regions = grid.label_regions(Connectivity::Four, |a, b| a == b);
+        assert_eq!(5, regions.count());
+        assert_eq!(Some(0), regions.label(Coord(1, 0)));
+        assert_eq!(Some(1), regions.label(Coord(1, 2)));
+        assert_eq!(Some(3), regions.label(Coord(2, 1)));
+        assert_eq!(None, regions.label(Coord(3, 0)));
+
+        let regions = grid.label_regions(Connectivity::Eight, |a, b| a == b);
+        assert_eq!(4, regions.count());
+        assert_eq!(Some(0), regions.label(Coord(2, 1)));
+    }
+
+    #[test]
+    fn test_metrics() {
+        let grid: Grid<char> = Grid::from_str("AAAA\nBBCD\nBBCC\nEEEC\n");
+        let metrics = grid.label_regions(Connectivity::Four, |a, b| a == b).metrics();
+
+        let area_sides = metrics
+            .iter()
+            .map(|m| (m.area, m.perimeter, m.sides))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![(4, 10, 4), (4, 8, 4), (4, 10, 8), (1, 4, 4), (3, 8, 4)],
+            area_sides
+        );
+        assert_eq!(Rect::new(Coord(1, 2), Coord(4, 4)), metrics[2].bounding_box);
+    }
+
+    #[test]
+    fn test_holes() {
+        let grid: Grid<char> = Grid::from_str("OOOOO\nOXOXO\nOOOOO\n");
+        let metrics = grid.label_regions(Connectivity::Four, |a, b| a == b).metrics();
+
+        assert_eq!(2, metrics[0].holes);
+        assert_eq!(12, metrics[0].sides);
+        assert_eq!(0, metrics[1].holes);
+
+        let grid: Grid<char> = Grid::from_str(".#.\n#.#\n.#.\n");
+        let four = grid.label_regions(Connectivity::Four, |a, b| a == b).metrics();
+        let eight = grid.label_regions(Connectivity::Eight, |a, b| a == b).metrics();
+
+        assert_eq!(0, four[1].holes);
+        assert_eq!(1, eight[1].holes);
+        assert_eq!(4, eight[1].area);
+    }
+}
@@ -0,0 +1,226 @@
+use super::{Connectivity, Coord, Grid, Rect};
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// An unbounded grid that only stores occupied cells.
+///
+/// The bounding box of all occupied cells is tracked on insertion and removal.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SparseGrid<T> {
+    map: HashMap<Coord, T>,
+    bounds: Option<Rect>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid {
+            map: HashMap::new(),
+            bounds: None,
+        }
+    }
+
+    /// Converts the cells of `grid` for which `keep` holds, placing the top-left cell at `origin`.
+    pub fn from_grid<F>(grid: Grid<T>, origin: Coord, keep: F) -> Self
+    where
+        F: Fn(&T) -> bool,
+    {
+        grid.into_indexed_iter()
+            .filter(|(_, elem)| keep(elem))
+            .map(|(idx, elem)| (origin + idx, elem))
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns the bounding box of all occupied cells.
+    pub fn bounds(&self) -> Option<Rect> {
+        self.bounds
+    }
+
+    pub fn contains(&self, idx: Coord) -> bool {
+        self.map.contains_key(&idx)
+    }
+
+    pub fn get(&self, idx: Coord) -> Option<&T> {
+        self.map.get(&idx)
+    }
+
+    pub fn get_mut(&mut self, idx: Coord) -> Option<&mut T> {
+        self.map.get_mut(&idx)
+    }
+
+    pub fn insert(&mut self, idx: Coord, elem: T) -> Option<T> {
+        self.bounds = Self::extend_bounds(self.bounds, idx);
+        self.map.insert(idx, elem)
+    }
+
+    pub fn remove(&mut self, idx: Coord) -> Option<T> {
+        let elem = self.map.remove(&idx)?;
+
+        if let Some(bounds) = self.bounds {
+            if idx.0 == bounds.min.0 || idx.1 == bounds.min.1 || idx.0 == bounds.max.0 - 1 || idx.1 == bounds.max.1 - 1
+            {
+                self.bounds = self
+                    .map
+                    .keys()
+                    .fold(None, |bounds, &idx| Self::extend_bounds(bounds, idx));
+            }
+        }
+
+        Some(elem)
+    }
+
+    /// Iterates over all occupied cells in unspecified order.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.map.iter().map(|(&idx, elem)| (idx, elem))
+    }
+
+    pub fn indexed_iter_mut(&mut self) -> impl Iterator<Item = (Coord, &mut T)> {
+        self.map.iter_mut().map(|(&idx, elem)| (idx, elem))
+    }
+
+    /// Iterates over the occupied neighbours of `idx`.
+    pub fn neighbours(&self, idx: Coord, connectivity: Connectivity) -> impl Iterator<Item = (Coord, &T)> {
+        connectivity
+            .offsets()
+            .iter()
+            .filter_map(move |&offset| self.get(idx + offset).map(|elem| (idx + offset, elem)))
+    }
+
+    /// Renders the bounding box row by row, mapping every cell to a character with `f`.
+    pub fn render<F>(&self, f: F) -> String
+    where
+        F: Fn(Option<&T>) -> char,
+    {
+        let mut s = String::new();
+
+        if let Some(bounds) = self.bounds {
+            for idx in bounds.iter() {
+                s.push(f(self.get(idx)));
+                if idx.1 == bounds.max.1 - 1 {
+                    s.push('\n');
+                }
+            }
+        }
+
+        s
+    }
+
+    /// Returns the top-left corner of the bounding box together with a dense copy of it.
+    pub fn to_grid(&self, fill: T) -> (Coord, Grid<T>)
+    where
+        T: Clone,
+    {
+        let Some(bounds) = self.bounds else {
+            return (Coord(0, 0), Grid::from_vec(Coord(0, 0), vec![]));
+        };
+
+        let mut grid = Grid::from_elem(bounds.dim(), fill);
+        for (&idx, elem) in &self.map {
+            grid[idx - bounds.min] = elem.clone();
+        }
+
+        (bounds.min, grid)
+    }
+
+    fn extend_bounds(bounds: Option<Rect>, idx: Coord) -> Option<Rect> {
+        Some(bounds.map_or_else(|| Rect::spanning(idx, idx), |bounds| bounds.extend(idx)))
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid::new()
+    }
+}
+
+impl<T> FromIterator<(Coord, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Coord, T)>>(iter: I) -> Self {
+        let mut sparse_grid = SparseGrid::new();
+        for (idx, elem) in iter {
+            sparse_grid.insert(idx, elem);
+        }
+        sparse_grid
+    }
+}
+
+impl<T> From<Grid<T>> for SparseGrid<T> {
+    fn from(grid: Grid<T>) -> Self {
+        SparseGrid::from_grid(grid, Coord(0, 0), |_| true)
+    }
+}
+
+impl<T> Index<Coord> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, idx: Coord) -> &Self::Output {
+        self.get(idx).unwrap()
+    }
+}
+
+impl<T> IndexMut<Coord> for SparseGrid<T> {
+    fn index_mut(&mut self, idx: Coord) -> &mut Self::Output {
+        self.get_mut(idx).unwrap()
+    }
+}
+
+impl Display for SparseGrid<bool> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.render(|elem| if elem == Some(&true) { '#' } else { '.' }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_and_bounds() {
+        let mut sparse_grid = SparseGrid::new();
+        assert_eq!(None, sparse_grid.bounds());
+
+        sparse_grid.insert(Coord(-2, 3), 'a');
+        sparse_grid.insert(Coord(1, -4), 'b');
+        sparse_grid.insert(Coord(0, 0), 'c');
+        assert_eq!(Some(Rect::new(Coord(-2, -4), Coord(2, 4))), sparse_grid.bounds());
+        assert_eq!(Some(&'a'), sparse_grid.get(Coord(-2, 3)));
+        assert_eq!(None, sparse_grid.get(Coord(-2, 2)));
+
+        assert_eq!(Some('b'), sparse_grid.remove(Coord(1, -4)));
+        assert_eq!(None, sparse_grid.remove(Coord(1, -4)));
+        assert_eq!(Some(Rect::new(Coord(-2, 0), Coord(1, 4))), sparse_grid.bounds());
+        assert_eq!(2, sparse_grid.len());
+    }
+
+    #[test]
+    fn test_neighbours_and_render() {
+        let sparse_grid = [Coord(-1, -1), Coord(-1, 0), Coord(0, 1)]
+            .into_iter()
+            .map(|idx| (idx, true))
+            .collect::<SparseGrid<_>>();
+
+        assert_eq!(2, sparse_grid.neighbours(Coord(0, 0), Connectivity::Four).count());
+        assert_eq!(3, sparse_grid.neighbours(Coord(0, 0), Connectivity::Eight).count());
+        assert_eq!("##.\n..#\n", sparse_grid.to_string());
+    }
+
+    #[test]
+    fn test_grid_conversion() {
+        let grid = Grid::from_vec(Coord(2, 3), vec![0, 1, 0, 2, 0, 0]);
+        let sparse_grid = SparseGrid::from_grid(grid, Coord(5, -5), |&elem| elem != 0);
+
+        assert_eq!(2, sparse_grid.len());
+        assert_eq!(2, sparse_grid[Coord(6, -5)]);
+
+        let (origin, dense) = sparse_grid.to_grid(0);
+        assert_eq!(Coord(5, -5), origin);
+        assert_eq!(Grid::from_vec(Coord(2, 2), vec![0, 1, 2, 0]), dense);
+    }
+}
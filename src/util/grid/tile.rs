@@ -0,0 +1,219 @@
+use super::{Coord, Grid};
+use crate::util::parse_matrix::MatrixError;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// A grid cell that is written as a single character.
+///
+/// Usually implemented with [`char_tile!`](crate::char_tile).
+pub trait CharTile: Sized {
+    fn from_char(c: char) -> Result<Self, UnknownTile>;
+
+    fn to_char(&self) -> char;
+}
+
+impl CharTile for char {
+    fn from_char(c: char) -> Result<Self, UnknownTile> {
+        Ok(c)
+    }
+
+    fn to_char(&self) -> char {
+        *self
+    }
+}
+
+impl CharTile for bool {
+    fn from_char(c: char) -> Result<Self, UnknownTile> {
+        match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(UnknownTile(c)),
+        }
+    }
+
+    fn to_char(&self) -> char {
+        if *self {
+            '#'
+        } else {
+            '.'
+        }
+    }
+}
+
+/// A character that does not name any tile.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct UnknownTile(pub char);
+
+impl Display for UnknownTile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown tile {:?}", self.0)
+    }
+}
+
+impl Error for UnknownTile {}
+
+/// Error of [`Grid::from_tiles`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TileError {
+    Unknown { tile: char, idx: Coord },
+    Ragged(MatrixError),
+}
+
+impl Display for TileError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TileError::Unknown { tile, idx } => write!(f, "unknown tile {:?} at row {}, col {}", tile, idx.0, idx.1),
+            TileError::Ragged(e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for TileError {}
+
+impl<T: CharTile> Grid<T> {
+    /// Parses a block of lines with [`CharTile::from_char`]. Line breaks may be `\n` or `\r\n` and
+    /// trailing line breaks are ignored, but all lines must have the same length.
+    pub fn from_tiles(s: &str) -> Result<Self, TileError> {
+        let mut vec = Vec::with_capacity(s.len());
+        let mut dim = Coord(0, 0);
+
+        for (row, line) in s.trim_end_matches(['\r', '\n']).lines().enumerate() {
+            let len_before = vec.len();
+            for (col, c) in line.chars().enumerate() {
+                let idx = Coord(row as isize, col as isize);
+                vec.push(T::from_char(c).map_err(|UnknownTile(tile)| TileError::Unknown { tile, idx })?);
+            }
+            let len = vec.len() - len_before;
+
+            if row == 0 {
+                dim.1 = len as isize;
+            } else if len as isize != dim.1 {
+                return Err(TileError::Ragged(MatrixError::Ragged {
+                    line: row + 1,
+                    len,
+                    expected: dim.1 as usize,
+                }));
+            }
+            dim.0 += 1;
+        }
+
+        Ok(Grid::from_vec(dim, vec))
+    }
+}
+
+impl<T: CharTile> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in 0..self.dim().0 {
+            for col in 0..self.dim().1 {
+                write!(f, "{}", self[Coord(row, col)].to_char())?
+            }
+            f.write_str("\n")?
+        }
+
+        Ok(())
+    }
+}
+
+/// Declares an enum whose variants are written as single characters, and implements
+/// [`CharTile`](crate::util::grid::CharTile) and `Display` for it:
+///
+/// ```ignore
+/// char_tile! {
+///     #[derive(Copy, Clone, Eq, PartialEq)]
+///     enum Location {
+///         Empty => '.',
+///         Wall = 2 => '#',
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! char_tile {
+    (
+        $(#[$attr: meta])*
+        $vis: vis enum $name: ident {
+            $($(#[$variant_attr: meta])* $variant: ident $(= $discriminant: expr)? => $c: literal),* $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis enum $name {
+            $($(#[$variant_attr])* $variant $(= $discriminant)?),*
+        }
+
+        impl $crate::util::grid::CharTile for $name {
+            fn from_char(c: char) -> Result<Self, $crate::util::grid::UnknownTile> {
+                match c {
+                    $($c => Ok($name::$variant),)*
+                    _ => Err($crate::util::grid::UnknownTile(c)),
+                }
+            }
+
+            fn to_char(&self) -> char {
+                match self {
+                    $($name::$variant => $c,)*
+                }
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                use std::fmt::Write;
+                f.write_char($crate::util::grid::CharTile::to_char(self))
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    char_tile! {
+        #[derive(Copy, Clone, Debug, Eq, PartialEq)]
+        enum Tile {
+            Empty => '.',
+            Wall => '#',
+            #[allow(dead_code)]
+            Start = 5 => 'S',
+        }
+    }
+
+    #[test]
+    fn test_char_tile() {
+        assert_eq!(Ok(Tile::Wall), Tile::from_char('#'));
+        assert_eq!(Err(UnknownTile('x')), Tile::from_char('x'));
+        assert_eq!('S', Tile::Start.to_char());
+        assert_eq!(5, Tile::Start as u8);
+        assert_eq!("S", Tile::Start.to_string());
+        assert_eq!("unknown tile 'x'", UnknownTile('x').to_string());
+    }
+
+    #[test]
+    fn test_from_tiles() {
+        let grid = Grid::<Tile>::from_tiles(".#\r\nS.\r\n").unwrap();
+        assert_eq!(Coord(2, 2), grid.dim());
+        assert_eq!(Tile::Start, grid[Coord(1, 0)]);
+        assert_eq!(".#\nS.\n", grid.to_string());
+
+        assert_eq!(
+            Err(TileError::Unknown {
+                tile: 'x',
+                idx: Coord(1, 1)
+            }),
+            Grid::<Tile>::from_tiles("..\n.x\n")
+        );
+        assert_eq!(
+            "unknown tile 'x' at row 1, col 1",
+            Grid::<Tile>::from_tiles("..\n.x\n").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 2 has 1 columns, expected 2",
+            Grid::<Tile>::from_tiles("..\n.\n").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let grid = Grid::<bool>::from_tiles("#.\n.#").unwrap();
+        assert_eq!("#.\n.#\n", grid.to_string());
+    }
+}
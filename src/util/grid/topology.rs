@@ -0,0 +1,150 @@
+use super::distance::{self, DistanceField};
+use super::walker::{self, Walk};
+use super::{Connectivity, Coord, Dir, Grid};
+use std::collections::VecDeque;
+
+/// Maps coordinates onto the cells of a grid-shaped space.
+pub trait Topology {
+    fn dim(&self) -> Coord;
+
+    /// Returns the cell addressed by `idx`, or `None` if `idx` lies outside of the space.
+    fn resolve(&self, idx: Coord) -> Option<Coord>;
+
+    fn adjacent(&self, idx: Coord, connectivity: Connectivity) -> impl Iterator<Item = Coord> {
+        connectivity
+            .offsets()
+            .iter()
+            .filter_map(move |&offset| self.resolve(idx + offset))
+    }
+
+    /// Visits all cells reachable from `start` over `passable` cells in breadth-first order,
+    /// yielding each cell with its step distance from `start`.
+    fn bfs<F>(&self, start: Coord, connectivity: Connectivity, passable: F) -> Bfs<'_, Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Coord) -> bool,
+    {
+        let mut visited = Grid::from_elem(self.dim(), false);
+        let mut queue = VecDeque::new();
+
+        if let Some(start) = self.resolve(start) {
+            visited[start] = true;
+            queue.push_back((start, 0));
+        }
+
+        Bfs {
+            topology: self,
+            connectivity,
+            passable,
+            visited,
+            queue,
+        }
+    }
+
+    /// Walks from `start` with heading `dir` until the walker leaves the space or repeats a state.
+    ///
+    /// On every cell `step` receives the current position and heading and returns the heading to
+    /// move on with, e.g. after turning at a wall.
+    fn walk<F>(&self, start: Coord, dir: Dir, step: F) -> Walk
+    where
+        Self: Sized,
+        F: FnMut(Coord, Dir) -> Dir,
+    {
+        walker::walk(self, start, dir, step)
+    }
+
+    /// Computes the step distance of every cell from the nearest of `sources` over orthogonally
+    /// adjacent `passable` cells. Unreachable cells are `None`.
+    fn distances<I, F>(&self, sources: I, passable: F) -> Grid<Option<u32>>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = Coord>,
+        F: FnMut(Coord) -> bool,
+    {
+        distance::distances(self, sources, passable)
+    }
+
+    /// Like [`Topology::distances`], but `cost` returns the cost of stepping from one cell to an
+    /// adjacent one, or `None` if the step is impossible.
+    fn weighted_distances<I, F>(&self, sources: I, cost: F) -> Grid<Option<u32>>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = Coord>,
+        F: FnMut(Coord, Coord) -> Option<u32>,
+    {
+        self.distance_field(sources, cost).into_dist()
+    }
+
+    /// Like [`Topology::weighted_distances`], but also records the direction back towards the
+    /// nearest source for path reconstruction.
+    fn distance_field<I, F>(&self, sources: I, cost: F) -> DistanceField
+    where
+        Self: Sized,
+        I: IntoIterator<Item = Coord>,
+        F: FnMut(Coord, Coord) -> Option<u32>,
+    {
+        distance::distance_field(self, sources, cost)
+    }
+}
+
+impl<T> Topology for Grid<T> {
+    fn dim(&self) -> Coord {
+        self.dim()
+    }
+
+    fn resolve(&self, idx: Coord) -> Option<Coord> {
+        if self.is_in_bounds(idx) {
+            Some(idx)
+        } else {
+            None
+        }
+    }
+}
+
+pub struct Bfs<'a, G, F> {
+    topology: &'a G,
+    connectivity: Connectivity,
+    passable: F,
+    visited: Grid<bool>,
+    queue: VecDeque<(Coord, usize)>,
+}
+
+impl<G, F> Iterator for Bfs<'_, G, F>
+where
+    G: Topology,
+    F: FnMut(Coord) -> bool,
+{
+    type Item = (Coord, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (idx, dist) = self.queue.pop_front()?;
+
+        for next_idx in self.topology.adjacent(idx, self.connectivity) {
+            if !self.visited[next_idx] && (self.passable)(next_idx) {
+                self.visited[next_idx] = true;
+                self.queue.push_back((next_idx, dist + 1));
+            }
+        }
+
+        Some((idx, dist))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bfs() {
+        let grid: Grid<char> = Grid::from_str("..#\n.##\n...\n");
+
+        let visited = grid
+            .bfs(Coord(0, 0), Connectivity::Four, |idx| grid[idx] == '.')
+            .collect::<Vec<_>>();
+
+        assert_eq!(6, visited.len());
+        assert_eq!((Coord(0, 0), 0), visited[0]);
+        assert_eq!(Some(&(Coord(2, 2), 4)), visited.last());
+        assert_eq!(0, grid.bfs(Coord(3, 0), Connectivity::Four, |_| true).count());
+    }
+}
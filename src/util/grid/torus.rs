@@ -0,0 +1,148 @@
+use super::{Connectivity, Coord, Grid, Topology};
+use std::fmt::{Display, Formatter};
+use std::ops::{Index, IndexMut};
+
+/// A [`Grid`] whose edges wrap around, so that every coordinate addresses a cell.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TorusGrid<T> {
+    grid: Grid<T>,
+}
+
+impl<T> TorusGrid<T> {
+    pub fn new(grid: Grid<T>) -> Self {
+        TorusGrid { grid }
+    }
+
+    pub fn from_elem(dim: Coord, elem: T) -> Self
+    where
+        T: Clone,
+    {
+        TorusGrid::new(Grid::from_elem(dim, elem))
+    }
+
+    pub fn into_inner(self) -> Grid<T> {
+        self.grid
+    }
+
+    pub fn grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+
+    pub fn dim(&self) -> Coord {
+        self.grid.dim()
+    }
+
+    /// Reduces `idx` into the bounds of the grid with Euclidean modulo.
+    pub fn wrap(&self, idx: Coord) -> Coord {
+        idx.rem_euclid(self.dim())
+    }
+
+    /// Returns `None` only if the grid is empty.
+    pub fn get(&self, idx: Coord) -> Option<&T> {
+        self.resolve(idx).and_then(|idx| self.grid.get(idx))
+    }
+
+    pub fn get_mut(&mut self, idx: Coord) -> Option<&mut T> {
+        self.resolve(idx).and_then(|idx| self.grid.get_mut(idx))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.grid.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.grid.iter_mut()
+    }
+
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.grid.indexed_iter()
+    }
+
+    pub fn neighbours(&self, idx: Coord, connectivity: Connectivity) -> impl Iterator<Item = (Coord, &T)> {
+        self.adjacent(idx, connectivity)
+            .map(move |next_idx| (next_idx, &self.grid[next_idx]))
+    }
+}
+
+impl<T> Topology for TorusGrid<T> {
+    fn dim(&self) -> Coord {
+        self.grid.dim()
+    }
+
+    fn resolve(&self, idx: Coord) -> Option<Coord> {
+        if self.grid.dim().0 == 0 || self.grid.dim().1 == 0 {
+            None
+        } else {
+            Some(self.wrap(idx))
+        }
+    }
+}
+
+impl<T> From<Grid<T>> for TorusGrid<T> {
+    fn from(grid: Grid<T>) -> Self {
+        TorusGrid::new(grid)
+    }
+}
+
+impl<T> Index<Coord> for TorusGrid<T> {
+    type Output = T;
+
+    fn index(&self, idx: Coord) -> &Self::Output {
+        self.get(idx).unwrap()
+    }
+}
+
+impl<T> IndexMut<Coord> for TorusGrid<T> {
+    fn index_mut(&mut self, idx: Coord) -> &mut Self::Output {
+        self.get_mut(idx).unwrap()
+    }
+}
+
+impl<T> Display for TorusGrid<T>
+where
+    Grid<T>: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.grid.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap_and_index() {
+        let mut torus = TorusGrid::new(Grid::from_vec(Coord(2, 3), vec![1, 2, 3, 4, 5, 6]));
+
+        assert_eq!(Coord(1, 2), torus.wrap(Coord(-1, -1)));
+        assert_eq!(Coord(0, 1), torus.wrap(Coord(4, 7)));
+        assert_eq!(6, torus[Coord(-1, -1)]);
+        assert_eq!(Some(&4), torus.get(Coord(3, 3)));
+
+        torus[Coord(2, -3)] = 9;
+        assert_eq!(9, torus.grid()[Coord(0, 0)]);
+
+        let empty = TorusGrid::from_elem(Coord(0, 3), 0);
+        assert_eq!(None, empty.get(Coord(0, 0)));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let torus = TorusGrid::new(Grid::from_vec(Coord(2, 3), vec![1, 2, 3, 4, 5, 6]));
+
+        let neighbours = torus.neighbours(Coord(0, 0), Connectivity::Four).collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                (Coord(1, 0), &4),
+                (Coord(0, 1), &2),
+                (Coord(1, 0), &4),
+                (Coord(0, 2), &3)
+            ],
+            neighbours
+        );
+
+        let farthest = torus.bfs(Coord(0, 0), Connectivity::Four, |_| true).last();
+        assert_eq!(Some((Coord(1, 2), 2)), farthest);
+    }
+}
@@ -0,0 +1,118 @@
+use super::{Coord, Dir, DirSet, Grid, Topology};
+
+/// How a [`Topology::walk`] ended.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum WalkOutcome {
+    /// The walker stepped out of the space after `steps` states.
+    Exit { steps: usize },
+    /// The walker returned to the state at step `entry_step`, repeating the last `cycle_len` states
+    /// forever.
+    Loop { entry_step: usize, cycle_len: usize },
+}
+
+/// The states visited by a [`Topology::walk`].
+#[derive(Clone, Debug)]
+pub struct Walk {
+    /// Every visited state in order, without the repeated state that closes a loop.
+    pub path: Vec<(Coord, Dir)>,
+    /// The headings the walker had on each cell.
+    pub visited: Grid<DirSet>,
+    pub outcome: WalkOutcome,
+}
+
+impl Walk {
+    pub fn is_loop(&self) -> bool {
+        matches!(self.outcome, WalkOutcome::Loop { .. })
+    }
+
+    pub fn visited_cells(&self) -> impl Iterator<Item = Coord> + '_ {
+        self.visited
+            .indexed_iter()
+            .filter(|(_, dirs)| !dirs.is_empty())
+            .map(|(idx, _)| idx)
+    }
+}
+
+pub(super) fn walk<G, F>(topology: &G, start: Coord, dir: Dir, mut step: F) -> Walk
+where
+    G: Topology,
+    F: FnMut(Coord, Dir) -> Dir,
+{
+    let mut path = vec![];
+    let mut visited = Grid::from_elem(topology.dim(), DirSet::EMPTY);
+    let mut state = topology.resolve(start).map(|start| (start, dir));
+
+    while let Some((idx, dir)) = state {
+        if !visited[idx].insert(dir) {
+            let entry_step = path.iter().position(|&visited| visited == (idx, dir)).unwrap();
+            let outcome = WalkOutcome::Loop {
+                entry_step,
+                cycle_len: path.len() - entry_step,
+            };
+            return Walk { path, visited, outcome };
+        }
+
+        path.push((idx, dir));
+        let next_dir = step(idx, dir);
+        state = topology.resolve(idx + next_dir).map(|next_idx| (next_idx, next_dir));
+    }
+
+    let outcome = WalkOutcome::Exit { steps: path.len() };
+    Walk { path, visited, outcome }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::grid::TorusGrid;
+
+    fn turn_right_at_walls(grid: &Grid<char>) -> impl FnMut(Coord, Dir) -> Dir + '_ {
+        |idx, mut dir| {
+            while grid.get(idx + dir) == Some(&'#') {
+                dir = dir.turn(1);
+            }
+            dir
+        }
+    }
+
+    #[test]
+    fn test_walk_exit() {
+        let grid: Grid<char> = Grid::from_str("...\n.#.\n...\n");
+
+        let walk = grid.walk(Coord(2, 1), Dir::N, turn_right_at_walls(&grid));
+        assert_eq!(WalkOutcome::Exit { steps: 2 }, walk.outcome);
+        assert_eq!(vec![Coord(2, 1), Coord(2, 2)], walk.visited_cells().collect::<Vec<_>>());
+        assert_eq!((Coord(2, 1), Dir::N), walk.path[0]);
+        assert!(walk.visited[Coord(2, 2)].contains(Dir::E));
+    }
+
+    #[test]
+    fn test_walk_loop() {
+        let grid: Grid<char> = Grid::from_str(".#..\n...#\n#...\n..#.\n");
+
+        let walk = grid.walk(Coord(2, 1), Dir::N, turn_right_at_walls(&grid));
+        assert_eq!(
+            WalkOutcome::Loop {
+                entry_step: 1,
+                cycle_len: 4
+            },
+            walk.outcome
+        );
+        assert!(walk.is_loop());
+        assert_eq!((Coord(2, 1), Dir::W), walk.path[4]);
+    }
+
+    #[test]
+    fn test_walk_torus() {
+        let torus = TorusGrid::from_elem(Coord(1, 3), ());
+
+        let walk = torus.walk(Coord(0, 1), Dir::E, |_, dir| dir);
+        assert_eq!(
+            WalkOutcome::Loop {
+                entry_step: 0,
+                cycle_len: 3
+            },
+            walk.outcome
+        );
+    }
+}
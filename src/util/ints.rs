@@ -0,0 +1,154 @@
+use crate::util::parse::ParseError;
+use arrayvec::ArrayVec;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// Iterator over the integers in a string, see [`ints`].
+#[derive(Clone, Debug)]
+pub struct Ints<'a, T> {
+    s: &'a str,
+    pos: usize,
+    _marker: PhantomData<T>,
+}
+
+impl<'a, T> Ints<'a, T> {
+    fn new(s: &'a str) -> Self {
+        Ints {
+            s,
+            pos: 0,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the next run of digits, including a directly preceding `-` or `+`, and its offset.
+    fn next_token(&mut self) -> Option<(usize, &'a str)> {
+        let bytes = self.s.as_bytes();
+        let digit_start = self.pos + bytes[self.pos..].iter().position(u8::is_ascii_digit)?;
+        let digit_end = digit_start
+            + bytes[digit_start..]
+                .iter()
+                .position(|b| !b.is_ascii_digit())
+                .unwrap_or(bytes.len() - digit_start);
+
+        let start = if digit_start > self.pos && matches!(bytes[digit_start - 1], b'-' | b'+') {
+            digit_start - 1
+        } else {
+            digit_start
+        };
+        self.pos = digit_end;
+
+        Some((start, &self.s[start..digit_end]))
+    }
+
+    fn count_remaining(&mut self) -> usize {
+        std::iter::from_fn(|| self.next_token()).count()
+    }
+}
+
+fn parse_token<T>(offset: usize, token: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: ToString,
+{
+    token.parse::<T>().map_err(|e| ParseError {
+        offset,
+        ..ParseError::new(std::any::type_name::<T>(), token, e.to_string())
+    })
+}
+
+impl<T> Iterator for Ints<'_, T>
+where
+    T: FromStr,
+    T::Err: ToString,
+{
+    type Item = Result<T, ParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_token().map(|(offset, token)| parse_token(offset, token))
+    }
+}
+
+/// Returns every integer in `s` in order, ignoring everything else. A `-` or `+` directly in
+/// front of the digits is part of the integer, so `p=0,4 v=3,-3` yields `0, 4, 3, -3`.
+///
+/// An integer that does not fit `T`, e.g. a negative number for an unsigned `T`, yields an error.
+pub fn ints<T>(s: &str) -> Ints<'_, T>
+where
+    T: FromStr,
+    T::Err: ToString,
+{
+    Ints::new(s)
+}
+
+/// Like [`ints`], but fails unless `s` contains exactly `N` integers that all fit `T`.
+pub fn ints_n<T, const N: usize>(s: &str) -> Result<[T; N], ParseError>
+where
+    T: FromStr,
+    T::Err: ToString,
+{
+    let mut iter = Ints::<T>::new(s);
+    let mut values = ArrayVec::<T, N>::new();
+
+    while let Some((offset, token)) = iter.next_token() {
+        let value = parse_token(offset, token)?;
+        values.try_push(value).map_err(|_| {
+            let count = N + 1 + iter.count_remaining();
+            ParseError::new("ints", s, format!("expected {} integers, found {}", N, count))
+        })?;
+    }
+
+    values
+        .into_inner()
+        .map_err(|values| ParseError::new("ints", s, format!("expected {} integers, found {}", N, values.len())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::parse::sections;
+
+    #[test]
+    fn test_ints() {
+        assert_eq!(Ok(vec![0, 4, 3, -3]), ints::<i32>("p=0,4 v=3,-3").collect());
+        assert_eq!(Ok(vec![94, 34]), ints::<i64>("Button A: X+94, Y+34").collect());
+        assert_eq!(Ok(vec![1, 2, 3]), ints::<u8>("-a1--b2\n\n3-").collect());
+        assert_eq!(None, ints::<u8>("no numbers - here").next());
+    }
+
+    #[test]
+    fn test_ints_errors() {
+        let mut iter = ints::<u8>("1 300 2");
+        assert_eq!(Some(Ok(1)), iter.next());
+        let e = iter.next().unwrap().unwrap_err();
+        assert_eq!(("u8", "300", 2), (e.pattern, e.input.as_str(), e.offset));
+        assert_eq!(Some(Ok(2)), iter.next());
+    }
+
+    #[test]
+    fn test_ints_n() {
+        let input = "Register A: 729\nRegister B: 0\n\nProgram: 0,1,5,4\n";
+        let [registers, program] = sections(input)[..] else {
+            panic!()
+        };
+
+        assert_eq!(Ok([729, 0]), ints_n::<u64, 2>(registers));
+        assert_eq!(Ok([0, 1, 5, 4]), ints_n::<u8, 4>(program));
+        assert_eq!(Ok([-1, 1]), ints_n::<i8, 2>("x=-1 y=+1"));
+    }
+
+    #[test]
+    fn test_ints_n_errors() {
+        assert_eq!(
+            "cannot parse \"1,2,3\" at byte 0 as `ints`: expected 2 integers, found 3",
+            ints_n::<u8, 2>("1,2,3").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "cannot parse \"1,2\" at byte 0 as `ints`: expected 3 integers, found 2",
+            ints_n::<u8, 3>("1,2").unwrap_err().to_string()
+        );
+
+        let e = ints_n::<u8, 2>("x=1, y=-2").unwrap_err();
+        assert_eq!(("u8", "-2", 7), (e.pattern, e.input.as_str(), e.offset));
+    }
+}
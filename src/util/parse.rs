@@ -1,29 +1,311 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Error of a [`try_parse!`](crate::try_parse) parser.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    /// The sub-pattern that failed.
+    pub pattern: &'static str,
+    /// The substring the sub-pattern failed on.
+    pub input: String,
+    /// Byte offset of `input` in the string passed to the outermost parser.
+    pub offset: usize,
+    /// Index of the blank-line separated section that `input` lies in, if the input was split
+    /// into sections.
+    pub section: Option<usize>,
+    pub reason: String,
+}
+
+impl ParseError {
+    pub fn new(pattern: &'static str, input: &str, reason: String) -> Self {
+        ParseError {
+            pattern,
+            input: input.to_string(),
+            offset: 0,
+            section: None,
+            reason,
+        }
+    }
+
+    /// Attributes the error to the `index`th piece of an input split at `delim`, if that split
+    /// produced sections.
+    pub fn in_piece(self, delim: Delim, index: usize) -> Self {
+        if delim == Delim::Sections && self.section.is_none() {
+            ParseError {
+                section: Some(index),
+                ..self
+            }
+        } else {
+            self
+        }
+    }
+
+    /// Moves the offset of an error of a parser that ran on `inner`, a substring of `outer`.
+    pub fn within(self, outer: &str, inner: &str) -> Self {
+        ParseError {
+            offset: self.offset + offset_of(outer, inner),
+            ..self
+        }
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if let Some(section) = self.section {
+            write!(f, "in section {}: ", section + 1)?;
+        }
+        write!(
+            f,
+            "cannot parse {:?} at byte {} as `{}`: {}",
+            self.input, self.offset, self.pattern, self.reason
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// How a pattern splits its input into pieces.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Delim {
+    Str(&'static str),
+    /// Any run of whitespace; leading and trailing whitespace is ignored.
+    Whitespace,
+    /// Line breaks with or without `\r`; a trailing line break is ignored.
+    Lines,
+    /// Runs of blank lines; leading and trailing blank lines are ignored.
+    Sections,
+}
+
+impl Display for Delim {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Delim::Str(s) => write!(f, "{:?}", s),
+            Delim::Whitespace => f.write_str("whitespace"),
+            Delim::Lines => f.write_str("line break"),
+            Delim::Sections => f.write_str("blank line"),
+        }
+    }
+}
+
+/// Splits strings at a [`Delim`], optionally trimming the pieces and dropping empty ones.
+#[derive(Copy, Clone, Debug)]
+pub struct Split {
+    delim: Delim,
+    trim: bool,
+    skip_empty: bool,
+}
+
+impl Split {
+    pub fn new(delim: Delim) -> Self {
+        Split {
+            delim,
+            trim: false,
+            skip_empty: false,
+        }
+    }
+
+    pub fn delim(&self) -> Delim {
+        self.delim
+    }
+
+    pub fn trim(self) -> Self {
+        Split { trim: true, ..self }
+    }
+
+    pub fn skip_empty(self) -> Self {
+        Split {
+            skip_empty: true,
+            ..self
+        }
+    }
+
+    pub fn split<'a>(&self, s: &'a str) -> Vec<&'a str> {
+        let pieces: Box<dyn Iterator<Item = &'a str>> = match self.delim {
+            Delim::Str(delim) => Box::new(s.split(delim)),
+            Delim::Whitespace => Box::new(s.split_whitespace()),
+            Delim::Lines => Box::new(s.lines()),
+            Delim::Sections => Box::new(sections(s).into_iter()),
+        };
+
+        pieces
+            .map(|piece| if self.trim { piece.trim() } else { piece })
+            .filter(|piece| !self.skip_empty || !piece.is_empty())
+            .collect()
+    }
+
+    /// Splits at the first delimiter.
+    pub fn split_once<'a>(&self, s: &'a str) -> Option<(&'a str, &'a str)> {
+        let (l, r) = match self.delim {
+            Delim::Str(delim) => s.split_once(delim)?,
+            Delim::Whitespace => {
+                let s = s.trim();
+                let (l, r) = s.split_once(char::is_whitespace)?;
+                (l, r.trim_start())
+            }
+            Delim::Lines => {
+                let (l, r) = s.split_once('\n')?;
+                let r = r.strip_suffix('\n').unwrap_or(r);
+                (l.strip_suffix('\r').unwrap_or(l), r.strip_suffix('\r').unwrap_or(r))
+            }
+            Delim::Sections => {
+                let sections = sections(s);
+                let (rest, last) = (sections.get(1)?, sections.last()?);
+                (sections[0], &s[offset_of(s, rest)..offset_of(s, last) + last.len()])
+            }
+        };
+
+        if self.trim {
+            Some((l.trim(), r.trim()))
+        } else {
+            Some((l, r))
+        }
+    }
+}
+
+/// Splits `s` into its blank-line separated sections, tolerating `\r\n` line breaks.
+///
+/// The sections do not include their final line break.
+pub fn sections(s: &str) -> Vec<&str> {
+    let mut sections = vec![];
+    let mut section_start = None;
+    let mut section_end = 0;
+    let mut pos = 0;
+
+    for line in s.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\r', '\n']);
+        if content.is_empty() {
+            if let Some(start) = section_start.take() {
+                sections.push(&s[start..section_end]);
+            }
+        } else {
+            section_start.get_or_insert(pos);
+            section_end = pos + content.len();
+        }
+        pos += line.len();
+    }
+
+    if let Some(start) = section_start {
+        sections.push(&s[start..section_end]);
+    }
+
+    sections
+}
+
+/// Like [`sections`], but fails unless there are exactly `N` sections.
+pub fn sections_n<const N: usize>(s: &str) -> Result<[&str; N], ParseError> {
+    sections(s).try_into().map_err(|sections: Vec<_>| {
+        ParseError::new(
+            "sections",
+            s,
+            format!("expected {} sections, found {}", N, sections.len()),
+        )
+    })
+}
+
+fn offset_of(outer: &str, inner: &str) -> usize {
+    let offset = (inner.as_ptr() as usize).wrapping_sub(outer.as_ptr() as usize);
+    if offset > outer.len() {
+        panic!("inner is not a substring of outer");
+    }
+
+    offset
+}
+
+/// Builds a `&str` parser closure returning `Result<_, ParseError>` from a pattern.
+///
+/// - `T`: any single-token `FromStr` type such as `u8`, `usize`, `char` or `String`
+/// - `[p delim flags...]`: splits on `delim` and parses every piece with `p` into a `Vec`
+/// - `[p delim n flags...]`: like `[p delim]`, but into an array of exactly `n` elements
+/// - `(p1 delim1 p2 delim2 p3 ...)`: splits on each delimiter once and parses into a tuple
+///
+/// A `delim` is a string literal, `ws` for any run of whitespace, `lines` for line breaks or
+/// `sections` for blank lines. The `trim` flag trims every piece and the `skip_empty` flag drops
+/// empty pieces.
 #[macro_export]
-macro_rules! parse {
-    ([$c: tt $delim: literal]) => {
-        |s: &str| s.split($delim).map(parse!($c)).collect::<Vec<_>>()
+macro_rules! try_parse {
+    (@delim ws) => {
+        $crate::util::parse::Delim::Whitespace
     };
-    ([$c: tt $delim: literal $n: literal]) => {
-        |s: &str| {
-            TryInto::<[_; $n]>::try_into(s.split($delim).map(parse!($c)).collect::<Vec<_>>()).unwrap()
+    (@delim lines) => {
+        $crate::util::parse::Delim::Lines
+    };
+    (@delim sections) => {
+        $crate::util::parse::Delim::Sections
+    };
+    (@delim $delim: literal) => {
+        $crate::util::parse::Delim::Str($delim)
+    };
+    (@tuple $pattern: expr, $top: ident, $s: ident, $index: ident [$($done: expr),*] $c: tt $delim: tt $($tail: tt)+) => {{
+        let split = $crate::util::parse::Split::new($crate::try_parse!(@delim $delim));
+        let (piece, $s) = split.split_once($s).ok_or_else(|| {
+            $crate::util::parse::ParseError::new($pattern, $s, format!("missing delimiter {}", split.delim()))
+                .within($top, $s)
+        })?;
+        let value = $crate::try_parse!($c)(piece).map_err(|e| e.within($top, piece).in_piece(split.delim(), $index))?;
+        let $index = $index + 1;
+        $crate::try_parse!(@tuple_rest $pattern, $top, $s, $index, split.delim(), [$($done,)* value] $($tail)+)
+    }};
+    // `$prev` is the delimiter that split `$s` off the previous piece.
+    (@tuple_rest $pattern: expr, $top: ident, $s: ident, $index: ident, $prev: expr, [$($done: expr),*] $c: tt) => {
+        Ok((
+            $($done,)*
+            $crate::try_parse!($c)($s).map_err(|e| e.within($top, $s).in_piece($prev, $index))?
+        ))
+    };
+    (@tuple_rest $pattern: expr, $top: ident, $s: ident, $index: ident, $prev: expr, [$($done: expr),*] $($tail: tt)+) => {
+        $crate::try_parse!(@tuple $pattern, $top, $s, $index [$($done),*] $($tail)+)
+    };
+    ([$c: tt $delim: tt $n: literal $($flag: ident)*]) => {
+        |s: &str| -> Result<_, $crate::util::parse::ParseError> {
+            TryInto::<[_; $n]>::try_into($crate::try_parse!([$c $delim $($flag)*])(s)?).map_err(|vec: Vec<_>| {
+                $crate::util::parse::ParseError::new(
+                    stringify!([$c $delim $n $($flag)*]),
+                    s,
+                    format!("expected {} elements, found {}", $n, vec.len()),
+                )
+            })
         }
     };
-    (($l: tt $delim: literal $r: tt)) => {
+    ([$c: tt $delim: tt $($flag: ident)*]) => {
         |s: &str| {
-            let (l, r) = s.split_once($delim).unwrap();
-            (parse!($l)(l), parse!($r)(r))
+            let split = $crate::util::parse::Split::new($crate::try_parse!(@delim $delim))$(.$flag())*;
+            split
+                .split(s)
+                .into_iter()
+                .enumerate()
+                .map(|(index, piece)| {
+                    $crate::try_parse!($c)(piece).map_err(|e| e.within(s, piece).in_piece(split.delim(), index))
+                })
+                .collect::<Result<Vec<_>, $crate::util::parse::ParseError>>()
         }
     };
-    (i32) => {
-        |s: &str| s.parse::<i32>().unwrap()
+    (($c: tt $($delim: tt $tail: tt)+)) => {
+        |s: &str| -> Result<_, $crate::util::parse::ParseError> {
+            let rest = s;
+            let index = 0;
+            $crate::try_parse!(@tuple stringify!(($c $($delim $tail)+)), s, rest, index [] $c $($delim $tail)+)
+        }
+    };
+    ($t: ty) => {
+        |s: &str| {
+            s.parse::<$t>()
+                .map_err(|e| $crate::util::parse::ParseError::new(stringify!($t), s, e.to_string()))
+        }
     };
-    (i64) => {
-        |s: &str| s.parse::<i64>().unwrap()
+}
+
+/// Like [`try_parse!`](crate::try_parse), but the parser panics with the [`ParseError`] message.
+#[macro_export]
+macro_rules! parse {
+    ($($pattern: tt)+) => {
+        |s: &str| $crate::try_parse!($($pattern)+)(s).unwrap_or_else(|e| panic!("{}", e))
     };
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+
     #[test]
     fn test() {
         assert_eq!(vec![1, 2, 3], parse!([i64 ","])("1,2,3"));
@@ -31,4 +313,123 @@ mod tests {
         assert_eq!([vec![1], vec![2, 3]], parse!([[i64 ","] ";" 2])("1;2,3"));
         assert_eq!(vec![[1, 2], [3, 4]], parse!([[i64 "," 2] ";"])("1,2;3,4"));
     }
+
+    #[test]
+    fn test_leaves() {
+        assert_eq!(vec![1u8, 255], parse!([u8 ","])("1,255"));
+        assert_eq!((7usize, -3isize), parse!((usize "," isize))("7,-3"));
+        assert_eq!(vec!['a', 'b'], parse!([char " "])("a b"));
+        assert_eq!(("key".to_string(), 10u64), parse!((String "=" u64))("key=10"));
+    }
+
+    #[test]
+    fn test_tuples() {
+        assert_eq!((1, 'x', 3), parse!((u32 "," char ":" i64))("1,x:3"));
+        assert_eq!(
+            (1, 2, [3, 4], vec![5]),
+            parse!((i32 " " i32 " " [i32 "," 2] " " [i32 ","]))("1 2 3,4 5")
+        );
+        assert_eq!(
+            vec![[[1, 2], [3, 4]], [[5, 6], [7, 8]]],
+            parse!([[[u8 "," 2] ";" 2] "|"])("1,2;3,4|5,6;7,8")
+        );
+    }
+
+    #[test]
+    fn test_whitespace_and_lines() {
+        assert_eq!(vec![(3, 4), (4, 3)], parse!([(i32 ws i32) lines])("3   4\r\n4 3\n"));
+        assert_eq!(vec![1, 2, 3], parse!([i64 ws])(" 1 2\t 3\n"));
+        assert_eq!(
+            ("a".to_string(), vec![1, 2]),
+            parse!((String lines [u8 ","]))("a\r\n1,2")
+        );
+        assert_eq!((1, 2), parse!((i32 lines i32))("1\r\n2\r\n"));
+        assert_eq!(
+            "cannot parse \"12\" at byte 0 as `(u8 ws u8)`: missing delimiter whitespace",
+            try_parse!((u8 ws u8))("12").unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn test_flags() {
+        assert_eq!(vec![1, 2, 3], parse!([i32 "," trim])("1, 2 ,3"));
+        assert_eq!(vec![1, 2], parse!([i32 "," skip_empty])("1,,2,"));
+        assert_eq!([1, 2], parse!([i32 "\n" 2 trim skip_empty])(" 1\n\n 2 \n"));
+        assert_eq!(vec!["a", "b"], parse!([String "|" trim skip_empty])(" a | | b "));
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = "1|2\r\n3|4\r\n\r\n5,6\r\n\r\n\r\n7\n";
+        assert_eq!(vec!["1|2\r\n3|4", "5,6", "7"], sections(input));
+        assert_eq!(Vec::<&str>::new(), sections("\n\n"));
+        assert_eq!(Ok(["a\nb", "c"]), sections_n("\na\nb\n\nc\n"));
+        assert_eq!(
+            "cannot parse \"a\" at byte 0 as `sections`: expected 2 sections, found 1",
+            sections_n::<2>("a").unwrap_err().to_string()
+        );
+
+        assert_eq!(
+            (vec![(1, 2), (3, 4)], vec![5, 6], 7),
+            parse!(([(i32 "|" i32) lines] sections [i32 ","] sections i32))(input)
+        );
+        assert_eq!(vec![vec![1, 2], vec![3]], parse!([[i32 lines] sections])("1\n2\n\n3\n"));
+    }
+
+    #[test]
+    fn test_sections_errors() {
+        let e = try_parse!(([i32 lines] sections [i32 lines]))("1\n2\n\n3\nx\n").unwrap_err();
+        assert_eq!((Some(1), 7), (e.section, e.offset));
+        assert_eq!(
+            "in section 2: cannot parse \"x\" at byte 7 as `i32`: invalid digit found in string",
+            e.to_string()
+        );
+
+        let e = try_parse!([[i32 ","] sections])("1\n\n2\n\n3,y").unwrap_err();
+        assert_eq!((Some(2), 8), (e.section, e.offset));
+
+        let e = try_parse!((i32 sections i32))("1\n\nz").unwrap_err();
+        assert_eq!(Some(1), e.section);
+
+        let e = try_parse!((i32 "," i32))("1,x").unwrap_err();
+        assert_eq!((None, 2), (e.section, e.offset));
+    }
+
+    #[test]
+    fn test_try_parse() {
+        assert_eq!(Ok((1, vec![2, 3])), try_parse!((i64 ";" [i32 ","]))("1;2,3"));
+
+        let e = try_parse!([(i32 "|" u8) "\n"])("1|2\n3|300\n4|5").unwrap_err();
+        assert_eq!("u8", e.pattern);
+        assert_eq!("300", e.input);
+        assert_eq!(6, e.offset);
+
+        let e = try_parse!([(i32 "|" i32 "|" i32) "\n"])("1|2|3\n4|5").unwrap_err();
+        assert_eq!("(i32 \"|\" i32 \"|\" i32)", e.pattern);
+        assert_eq!("5", e.input);
+        assert_eq!(8, e.offset);
+        assert_eq!(
+            "cannot parse \"5\" at byte 8 as `(i32 \"|\" i32 \"|\" i32)`: missing delimiter \"|\"",
+            e.to_string()
+        );
+
+        let e = try_parse!([u8 "," 2])("1,2,3").unwrap_err();
+        assert_eq!(("1,2,3", 0), (e.input.as_str(), e.offset));
+    }
+
+    #[test]
+    fn test_try_parse_anyhow() {
+        fn parse_pair(s: &str) -> anyhow::Result<(u8, u8)> {
+            Ok(try_parse!((u8 "-" u8))(s)?)
+        }
+
+        assert_eq!((1, 2), parse_pair("1-2").unwrap());
+        assert!(parse_pair("1-x").unwrap_err().to_string().contains("at byte 2"));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot parse \"x\" at byte 2 as `i32`")]
+    fn test_parse_panics() {
+        parse!([i32 ","])("1,x");
+    }
 }
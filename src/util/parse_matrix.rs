@@ -1,24 +1,118 @@
 use ndarray::Array2;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
 
-pub trait ToMatrix<T, F>
-where
-    F: Fn(char) -> T,
-{
-    fn parse_matrix(&self, f: F) -> Array2<T>;
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MatrixError {
+    /// Line `line` (1-based) has `len` characters instead of the `expected` ones of the first line.
+    Ragged { line: usize, len: usize, expected: usize },
 }
 
-impl<T, F> ToMatrix<T, F> for &str
-where
-    F: Fn(char) -> T,
-{
-    fn parse_matrix(&self, f: F) -> Array2<T> {
-        let col_count = self.find("\n").unwrap();
-        let row_count = self.len() / (col_count + 1);
-
-        let vec = self
-            .chars()
-            .filter_map(|c| if c == '\n' { None } else { Some(f(c)) })
-            .collect::<Vec<T>>();
-        Array2::from_shape_vec((row_count, col_count), vec).unwrap()
+impl Display for MatrixError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MatrixError::Ragged { line, len, expected } => {
+                write!(f, "line {} has {} columns, expected {}", line, len, expected)
+            }
+        }
+    }
+}
+
+impl Error for MatrixError {}
+
+pub trait ToMatrix {
+    /// Parses every character of a block of lines with `f`. Line breaks may be `\n` or `\r\n` and
+    /// trailing line breaks are ignored, but all lines must have the same length.
+    fn parse_matrix<T, F>(&self, f: F) -> Result<Array2<T>, MatrixError>
+    where
+        F: FnMut(char) -> T;
+
+    /// Like [`ToMatrix::parse_matrix`], but also passes the `[row, col]` position to `f`.
+    fn parse_matrix_indexed<T, F>(&self, f: F) -> Result<Array2<T>, MatrixError>
+    where
+        F: FnMut([usize; 2], char) -> T;
+}
+
+impl ToMatrix for &str {
+    fn parse_matrix<T, F>(&self, mut f: F) -> Result<Array2<T>, MatrixError>
+    where
+        F: FnMut(char) -> T,
+    {
+        self.parse_matrix_indexed(|_, c| f(c))
+    }
+
+    fn parse_matrix_indexed<T, F>(&self, mut f: F) -> Result<Array2<T>, MatrixError>
+    where
+        F: FnMut([usize; 2], char) -> T,
+    {
+        let mut vec = Vec::with_capacity(self.len());
+        let mut row_count = 0;
+        let mut col_count = 0;
+
+        for (row, line) in self.trim_end_matches(['\r', '\n']).lines().enumerate() {
+            let len_before = vec.len();
+            vec.extend(line.chars().enumerate().map(|(col, c)| f([row, col], c)));
+            let len = vec.len() - len_before;
+
+            if row == 0 {
+                col_count = len;
+            } else if len != col_count {
+                return Err(MatrixError::Ragged {
+                    line: row + 1,
+                    len,
+                    expected: col_count,
+                });
+            }
+            row_count += 1;
+        }
+
+        Ok(Array2::from_shape_vec((row_count, col_count), vec).unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ndarray::array;
+
+    #[test]
+    fn test_parse_matrix() {
+        let expected = array![[1, 2, 3], [4, 5, 6]];
+        for input in ["123\n456\n", "123\n456", "123\r\n456\r\n", "123\n456\n\n"] {
+            assert_eq!(Ok(expected.clone()), input.parse_matrix(|c| c.to_digit(10).unwrap()));
+        }
+
+        assert_eq!(
+            Ok(Array2::<char>::from_shape_vec((0, 0), vec![]).unwrap()),
+            "".parse_matrix(|c| c)
+        );
+    }
+
+    #[test]
+    fn test_parse_matrix_indexed() {
+        let mut start = None;
+        let matrix = ".#\nS.\n".parse_matrix_indexed(|pos, c| {
+            if c == 'S' {
+                start = Some(pos);
+            }
+            c == '#'
+        });
+
+        assert_eq!(Ok(array![[false, true], [false, false]]), matrix);
+        assert_eq!(Some([1, 0]), start);
+    }
+
+    #[test]
+    fn test_parse_matrix_ragged() {
+        let e = "123\n456\n78\n".parse_matrix(|c| c).unwrap_err();
+        assert_eq!(
+            MatrixError::Ragged {
+                line: 3,
+                len: 2,
+                expected: 3
+            },
+            e
+        );
+        assert_eq!("line 3 has 2 columns, expected 3", e.to_string());
     }
 }
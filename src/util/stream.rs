@@ -0,0 +1,179 @@
+use crate::util::parse::ParseError;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::io::BufRead;
+
+/// Error while parsing input from a [`BufRead`].
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    /// A parser failed on line `line` (1-based); the offset of `error` is relative to that line.
+    Parse {
+        line: usize,
+        error: ParseError,
+    },
+}
+
+impl Display for StreamError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StreamError::Io(e) => write!(f, "cannot read input: {}", e),
+            StreamError::Parse { line, error } => write!(f, "line {}: {}", line, error),
+        }
+    }
+}
+
+impl Error for StreamError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            StreamError::Io(e) => Some(e),
+            StreamError::Parse { error, .. } => Some(error),
+        }
+    }
+}
+
+impl From<io::Error> for StreamError {
+    fn from(e: io::Error) -> Self {
+        StreamError::Io(e)
+    }
+}
+
+/// Iterator over the parsed lines of a reader, see [`parse_lines`].
+pub struct ParseLines<R, F> {
+    reader: R,
+    parser: F,
+    buf: String,
+    line: usize,
+}
+
+impl<R, F, T> Iterator for ParseLines<R, F>
+where
+    R: BufRead,
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    type Item = Result<T, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            self.buf.clear();
+            match self.reader.read_line(&mut self.buf) {
+                Ok(0) => return None,
+                Ok(_) => self.line += 1,
+                Err(e) => return Some(Err(e.into())),
+            }
+
+            let content = self.buf.trim_end_matches(['\r', '\n']);
+            if !content.is_empty() {
+                let line = self.line;
+                return Some((self.parser)(content).map_err(|error| StreamError::Parse { line, error }));
+            }
+        }
+    }
+}
+
+/// Parses every non-empty line of `reader` with `parser`, e.g. a [`try_parse!`](crate::try_parse)
+/// closure. Only one line is held in memory at a time.
+pub fn parse_lines<R, F, T>(reader: R, parser: F) -> ParseLines<R, F>
+where
+    R: BufRead,
+    F: FnMut(&str) -> Result<T, ParseError>,
+{
+    ParseLines {
+        reader,
+        parser,
+        buf: String::new(),
+        line: 0,
+    }
+}
+
+/// Iterator over the digits of a reader, see [`digits`].
+pub struct Digits<R> {
+    reader: R,
+    line: usize,
+    offset: usize,
+}
+
+impl<R: BufRead> Iterator for Digits<R> {
+    type Item = Result<u8, StreamError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let byte = match self.reader.fill_buf() {
+                Ok([]) => return None,
+                Ok(buf) => buf[0],
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Some(Err(e.into())),
+            };
+            self.reader.consume(1);
+
+            match byte {
+                b'0'..=b'9' => {
+                    self.offset += 1;
+                    return Some(Ok(byte - b'0'));
+                }
+                b'\n' => {
+                    self.line += 1;
+                    self.offset = 0;
+                }
+                b'\r' | b' ' | b'\t' => self.offset += 1,
+                _ => {
+                    let mut error = ParseError::new("digit", &(byte as char).to_string(), "not a digit".to_string());
+                    error.offset = self.offset;
+                    return Some(Err(StreamError::Parse { line: self.line, error }));
+                }
+            }
+        }
+    }
+}
+
+/// Returns the value of every digit in `reader`, skipping whitespace and line breaks, without
+/// reading whole lines into memory.
+pub fn digits<R: BufRead>(reader: R) -> Digits<R> {
+    Digits {
+        reader,
+        line: 1,
+        offset: 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::try_parse;
+
+    #[test]
+    fn test_parse_lines() {
+        let input = "1 2\r\n\n3 4\n".as_bytes();
+
+        let values = parse_lines(input, try_parse!((i32 ws i32)))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(vec![(1, 2), (3, 4)], values);
+    }
+
+    #[test]
+    fn test_parse_lines_error() {
+        let input = "1\n2\nx3\n".as_bytes();
+
+        let e = parse_lines(input, try_parse!(i64)).nth(2).unwrap().unwrap_err();
+        assert!(matches!(&e, StreamError::Parse { line: 3, error } if error.input == "x3"));
+        assert!(e
+            .to_string()
+            .starts_with("line 3: cannot parse \"x3\" at byte 0 as `i64`"));
+    }
+
+    #[test]
+    fn test_digits() {
+        let input = "2333133121414131402\n".as_bytes();
+        let values = digits(input).collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(19, values.len());
+        assert_eq!(&[2, 3, 3, 3, 1], &values[..5]);
+
+        let e = digits("12\n3x\n".as_bytes()).nth(3).unwrap().unwrap_err();
+        assert_eq!(
+            "line 2: cannot parse \"x\" at byte 1 as `digit`: not a digit",
+            e.to_string()
+        );
+    }
+}
@@ -0,0 +1,95 @@
+use aoc2024::generator::{Rng, GENERATORS};
+use std::env;
+use std::fs;
+use std::process::Command;
+
+const SEEDS: u64 = 8;
+const SIZE: usize = 40;
+
+/// The solver binary of every day that has a generator.
+const SOLVERS: &[(u32, &str)] = &[
+    (1, env!("CARGO_BIN_EXE_day01")),
+    (2, env!("CARGO_BIN_EXE_day02")),
+    (3, env!("CARGO_BIN_EXE_day03")),
+    (4, env!("CARGO_BIN_EXE_day04")),
+    (5, env!("CARGO_BIN_EXE_day05")),
+    (6, env!("CARGO_BIN_EXE_day06")),
+    (7, env!("CARGO_BIN_EXE_day07")),
+    (8, env!("CARGO_BIN_EXE_day08")),
+    (9, env!("CARGO_BIN_EXE_day09")),
+    (10, env!("CARGO_BIN_EXE_day10")),
+    (11, env!("CARGO_BIN_EXE_day11")),
+    (12, env!("CARGO_BIN_EXE_day12")),
+    (13, env!("CARGO_BIN_EXE_day13")),
+    (14, env!("CARGO_BIN_EXE_day14")),
+    (15, env!("CARGO_BIN_EXE_day15")),
+    (16, env!("CARGO_BIN_EXE_day16")),
+    (17, env!("CARGO_BIN_EXE_day17")),
+    (18, env!("CARGO_BIN_EXE_day18")),
+    (19, env!("CARGO_BIN_EXE_day19")),
+    (20, env!("CARGO_BIN_EXE_day20")),
+    (21, env!("CARGO_BIN_EXE_day21")),
+    (22, env!("CARGO_BIN_EXE_day22")),
+    (23, env!("CARGO_BIN_EXE_day23")),
+];
+
+fn solver(day: u32) -> &'static str {
+    SOLVERS
+        .iter()
+        .find_map(|&(solver_day, solver)| (solver_day == day).then_some(solver))
+        .unwrap_or_else(|| panic!("no solver registered for day {}", day))
+}
+
+/// Extracts the answer of `part` from a solver's output, e.g. `A: 42` or `A: 42 in 1.2ms`.
+fn answer<'a>(output: &'a str, part: &str) -> Option<&'a str> {
+    output.lines().find_map(|line| {
+        let answer = line.strip_prefix(part)?.strip_prefix(": ")?;
+        Some(answer.split_once(" in ").map_or(answer, |(answer, _)| answer))
+    })
+}
+
+#[test]
+fn test_generators_and_solvers_cover_the_same_days() {
+    let days = GENERATORS.iter().map(|generator| generator.day).collect::<Vec<_>>();
+    let solver_days = SOLVERS.iter().map(|&(day, _)| day).collect::<Vec<_>>();
+    assert_eq!(solver_days, days);
+}
+
+#[test]
+fn test_solvers_match_generators() {
+    let dir = env::temp_dir().join(format!("aoc2024-generators-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    for generator in GENERATORS {
+        for seed in 0..SEEDS {
+            let generated = (generator.generate)(&mut Rng::new(seed), SIZE);
+            let path = dir.join(format!("day{:02}-{}.txt", generator.day, seed));
+            fs::write(&path, &generated.input).unwrap();
+
+            let output = Command::new(solver(generator.day)).arg(&path).output().unwrap();
+            let stdout = String::from_utf8(output.stdout).unwrap();
+            assert!(
+                output.status.success(),
+                "day {} seed {}: {}",
+                generator.day,
+                seed,
+                String::from_utf8_lossy(&output.stderr)
+            );
+
+            for (part, expected) in [("A", &generated.a), ("B", &generated.b)] {
+                if let Some(expected) = expected {
+                    assert_eq!(
+                        Some(expected.as_str()),
+                        answer(&stdout, part),
+                        "day {} seed {} part {}",
+                        generator.day,
+                        seed,
+                        part
+                    );
+                }
+            }
+        }
+    }
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,54 @@
+use std::env;
+use std::fs;
+use std::process::{Command, Output};
+
+fn run(solver: &str, path: &str) -> Output {
+    Command::new(solver).arg(path).output().unwrap()
+}
+
+fn stdout_lines(output: &Output) -> Vec<String> {
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout.clone())
+        .unwrap()
+        .lines()
+        .filter(|line| !line.starts_with("Parsed in"))
+        .map(|line| line.split_once(" in ").map_or(line, |(answer, _)| answer).to_string())
+        .collect()
+}
+
+#[test]
+fn test_aoc_day_reads_path() {
+    let output = run(
+        env!("CARGO_BIN_EXE_day09"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/src/bin/day09/test.txt"),
+    );
+    assert_eq!(vec!["A: 1928", "B: 2858"], stdout_lines(&output));
+}
+
+#[test]
+fn test_aoc_day_reads_path_line_by_line() {
+    let test_input = include_str!("../src/bin/day22/test.txt");
+    let (input_a, _) = test_input.split_once("=====\n").unwrap();
+    let path = env::temp_dir().join(format!("aoc2024-day22-{}.txt", std::process::id()));
+    fs::write(&path, input_a).unwrap();
+
+    let output = run(env!("CARGO_BIN_EXE_day22"), path.to_str().unwrap());
+    fs::remove_file(&path).unwrap();
+    assert_eq!("A: 37327623", stdout_lines(&output)[0]);
+}
+
+#[test]
+fn test_init_reads_path() {
+    let output = run(
+        env!("CARGO_BIN_EXE_day01"),
+        concat!(env!("CARGO_MANIFEST_DIR"), "/src/bin/day01/test.txt"),
+    );
+    assert_eq!(vec!["A: 11", "B: 31"], stdout_lines(&output));
+}
+
+#[test]
+fn test_missing_path() {
+    let output = run(env!("CARGO_BIN_EXE_day09"), "does/not/exist.txt");
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("cannot open does/not/exist.txt"));
+}